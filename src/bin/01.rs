@@ -51,15 +51,49 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    advent_of_code::solution_tests! {
+        part_one {
+            example => Some(3),
+        }
+        part_two {
+            example => Some(6),
+        }
+    }
+
+    use advent_of_code::template::property::{self, Rng};
+
+    fn generate(rng: &mut Rng) -> String {
+        (0..rng.range(1..=20))
+            .map(|_| format!("{}{}\n", rng.choose(&["L", "R"]), rng.range(0..=350)))
+            .collect()
+    }
+
+    /// Turn the dial one click at a time, returning the zeroes landed on and passed.
+    fn simulate(input: &str) -> (u64, u64) {
+        let (mut landed, mut passed) = (0, 0);
+        let mut position = 50;
+        for line in input.lines() {
+            let step = if line.starts_with('L') { 99 } else { 1 };
+            for _ in 0..line[1..].parse::<u32>().unwrap() {
+                position = (position + step) % 100;
+                if position == 0 {
+                    passed += 1;
+                }
+            }
+            if position == 0 {
+                landed += 1;
+            }
+        }
+        (landed, passed)
+    }
+
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+    fn part_one_matches_simulation() {
+        property::check(generate, part_one, |input| Some(simulate(input).0));
     }
 
     #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
+    fn part_two_matches_simulation() {
+        property::check(generate, part_two, |input| Some(simulate(input).1));
     }
 }
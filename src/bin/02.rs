@@ -1,62 +1,99 @@
 advent_of_code::solution!(2);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut result = 0;
-    let ranges = input.split(",");
-    for range in ranges {
-        let mut range_iterator = range.split("-");
-        let range_start = u64::from_str_radix(range_iterator.next().unwrap(), 10).unwrap();
-        let range_end = u64::from_str_radix(range_iterator.next().unwrap(), 10).unwrap();
-
-        for value in range_start..=range_end {
-            let mut value_left_part = value.to_string();
-            let value_len = value_left_part.len();
-            if value_len % 2 != 0 {continue;} // some early optimisation
-            let value_right_part = value_left_part.split_off(value_len/2);
-            if value_left_part == value_right_part {
-                result += value;
-            }
-        }
-    }
-    return Some(result);
+use std::collections::BTreeSet;
+
+use advent_of_code::interval::IntervalSet;
+
+fn parse_ranges(input: &str) -> IntervalSet<u64> {
+    input.trim().split(",").map(|range|{
+        let (start, end) = range.split_once("-").unwrap();
+        u64::from_str_radix(start, 10).unwrap()..=u64::from_str_radix(end, 10).unwrap()
+    }).collect()
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut result = 0;
-    let ranges = input.split(",");
-    for range in ranges {
-        let mut range_iterator = range.split("-");
-        let range_start = u64::from_str_radix(range_iterator.next().unwrap(), 10).unwrap();
-        let range_end = u64::from_str_radix(range_iterator.next().unwrap(), 10).unwrap();
-
-        'value_generator: for value in range_start..=range_end {
-            let value_str = value.to_string();
-            let value_len = value_str.len();
-            for split_size in 1..=value_len/2 {
-                if value_len.rem_euclid(split_size) != 0 {continue;} // if we can't divide into whole chunks then just skip
-                if value_str == value_str.split_at(split_size).0.repeat(value_len/split_size) {
-                    result += value;
-                    continue 'value_generator; // we added this value so no need to check other split_size'es
+/// Sums the ids inside `ranges` that are a sequence of digits repeated a number of times accepted by `accept_repeats`.
+/// Instead of checking every id in the ranges we only generate the repeated ones and look them up.
+fn sum_repeated_ids(ranges: &IntervalSet<u64>, accept_repeats: impl Fn(u32) -> bool) -> u64 {
+    let Some(max) = ranges.iter().last().map(|range| *range.end()) else {return 0;};
+    let max_digits = max.to_string().len() as u32;
+    let mut found = BTreeSet::new(); // 1111 is both 1 and 11 repeated, but should only be counted once
+    for pattern_len in 1..=max_digits/2 {
+        for repeats in 2..=max_digits/pattern_len {
+            if !accept_repeats(repeats) {continue;}
+            // e.g. multiplying a 3 digit pattern by 1001001 repeats it three times
+            let multiplier: u64 = (0..repeats).map(|i| 10u64.pow(pattern_len*i)).sum();
+            for pattern in 10u64.pow(pattern_len-1)..10u64.pow(pattern_len) {
+                let Some(id) = pattern.checked_mul(multiplier).filter(|&id| id <= max) else {break;};
+                if ranges.contains(id) {
+                    found.insert(id);
                 }
             }
         }
     }
-    return Some(result);
+    return found.iter().sum();
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let ranges = parse_ranges(input);
+    return Some(sum_repeated_ids(&ranges, |repeats| repeats == 2));
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let ranges = parse_ranges(input);
+    return Some(sum_repeated_ids(&ranges, |_| true));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    advent_of_code::solution_tests! {
+        part_one {
+            example => Some(1227775554),
+        }
+        part_two {
+            example => Some(4174379265),
+        }
+    }
+
+    use std::collections::BTreeSet;
+
+    use advent_of_code::template::property::{self, Rng};
+
+    fn generate(rng: &mut Rng) -> String {
+        let ranges: Vec<String> = (0..rng.range(1..=5)).map(|_|{
+            let start = rng.range(1..=200_000);
+            format!("{start}-{}", start + rng.range(0..=1_000))
+        }).collect();
+        ranges.join(",")
+    }
+
+    /// Checks every id in the ranges, like the original solution did.
+    fn brute_force(input: &str, accept: impl Fn(&str, usize) -> bool) -> Option<u64> {
+        let mut ids = BTreeSet::new();
+        for range in input.trim().split(",") {
+            let (start, end) = range.split_once("-").unwrap();
+            let (start, end): (u64, u64) = (start.parse().unwrap(), end.parse().unwrap());
+            assert!(start <= end);
+            ids.extend(start..=end);
+        }
+        Some(ids.into_iter().filter(|id|{
+            let id = id.to_string();
+            (1..=id.len()/2).any(|split| id.len() % split == 0 && accept(&id, split))
+        }).sum())
+    }
+
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
+    fn part_one_matches_brute_force() {
+        property::check(generate, part_one, |input| brute_force(input, |id, split|{
+            split * 2 == id.len() && id[..split] == id[split..]
+        }));
     }
 
     #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
+    fn part_two_matches_brute_force() {
+        property::check(generate, part_two, |input| brute_force(input, |id, split|{
+            id == id[..split].repeat(id.len() / split)
+        }));
     }
 }
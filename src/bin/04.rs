@@ -1,48 +1,34 @@
 advent_of_code::solution!(4);
 
+use advent_of_code::grid::Grid;
+
 struct RemovalResult {
-    new_state: Vec<Vec<bool>>,
+    new_state: Grid<bool>,
     removed_rolls: u64,
 }
 
-fn remove_rolls(state: Vec<Vec<bool>>) -> RemovalResult {
+fn remove_rolls(state: Grid<bool>) -> RemovalResult {
     let mut new_state = state.clone();
     let mut removed_rolls = 0u64;
-    let y_len = state.len() as isize;
-    let x_len = state[0].len() as isize;
-    for y in 0isize..y_len {
-        for x in 0isize..x_len {
-            if !state[x as usize][y as usize] {continue;}
-            let mut neighbors = 0;
-            for y_offset in -1..=1isize {
-                for x_offset in -1..=1isize {
-                    if x_offset == 0 && y_offset == 0 {continue;} // don't count itself
-                    let checked_x = x+x_offset;
-                    let checked_y = y+y_offset;
-                    if checked_x < 0 || checked_y < 0 || checked_x >= x_len || checked_y >= y_len {continue;}
-                    if state[checked_x as usize][checked_y as usize] {
-                        neighbors += 1;
-                    }
-                }
-            }
-            if neighbors < 4 {
-                removed_rolls += 1;
-                new_state[x as usize][y as usize] = false;
-            }
+    for (x, y) in state.positions() {
+        if !state[(x, y)] {continue;}
+        let neighbors = state.neighbours_8(x, y)
+            .filter(|&position| state[position])
+            .count();
+        if neighbors < 4 {
+            removed_rolls += 1;
+            new_state[(x, y)] = false;
         }
     };
     return RemovalResult { new_state, removed_rolls };
 }
 
+fn parse_rolls(input: &str) -> Grid<bool> {
+    Grid::parse(input, |char| char == '@').unwrap()
+}
+
 pub fn part_one(input: &str) -> Option<u64> {
-    let rolls: Vec<Vec<bool>> = input.lines().map(|line| {
-        line.chars().map(|char| {
-            match char {
-                '@' => true,
-                 _  => false,
-            }
-        }).collect()
-    }).collect();
+    let rolls = parse_rolls(input);
 
     let removal_result = remove_rolls(rolls);
     return Some(removal_result.removed_rolls);
@@ -50,14 +36,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 pub fn part_two(input: &str) -> Option<u64> {
     let mut result = 0u64;
-    let mut state: Vec<Vec<bool>> = input.lines().map(|line| {
-        line.chars().map(|char| {
-            match char {
-                '@' => true,
-                 _  => false,
-            }
-        }).collect()
-    }).collect();
+    let mut state = parse_rolls(input);
 
     loop {
         let removal_result = remove_rolls(state);
@@ -72,15 +51,12 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(43));
+    advent_of_code::solution_tests! {
+        part_one {
+            example => Some(13),
+        }
+        part_two {
+            example => Some(43),
+        }
     }
 }
@@ -1,80 +1,22 @@
 advent_of_code::solution!(5);
 
-struct RangeStorage {
-    ranges: Vec<Range>,
-}
-
-impl RangeStorage {
-    fn add(&mut self, start: u64, end: u64) {
-        self.ranges.push(Range {
-            start,
-            end,
-        });
-    }
-
-    fn is_inside(&self, value: u64) -> bool {
-        for range in &self.ranges {
-            if range.is_inside(value) {
-                return true;
-            }
-        };
-        return false;
-    }
-
-    fn optimise(&mut self) {
-        'one_more_time: loop {
-            for i in 0..self.ranges.len()-1 {
-                for j in i+1..self.ranges.len() {
-                    let i_range = self.ranges[i];
-                    let j_range = self.ranges[j];
-                    if (i_range.start <= j_range.end) && (j_range.start <= i_range.end) {
-                        self.ranges.remove(j);
-                        self.ranges.remove(i);
-                        self.ranges.insert(i, Range {
-                            start: i_range.start.min(j_range.start),
-                            end: i_range.end.max(j_range.end)
-                        });
-                        continue 'one_more_time;
-                        // We changed vec length, so not to index out of bounds the easiest solution is to just iterate again.
-                        // This will not affect performance in the slightest since there are 190 ranges to begin with, so at most
-                        // it will run 189 addition times which is like 100µs tops
-                    }
-                }
-            }
-            break;
-        }
-    }
+use advent_of_code::interval::IntervalSet;
 
-    fn new() -> Self {Self { ranges: vec![] }}
-}
-
-#[derive(Clone, Copy)]
-struct Range {
-    start: u64,
-    end: u64,
-}
-
-impl Range {
-    fn is_inside(&self, value: u64) -> bool {
-        return value >= self.start && value <= self.end;
-    }
+fn parse_ranges(ranges: &str) -> IntervalSet<u64> {
+    ranges.lines().map(|line|{
+        let (start, end) = line.split_once("-").unwrap();
+        u64::from_str_radix(start, 10).unwrap()..=u64::from_str_radix(end, 10).unwrap()
+    }).collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (ranges, ids) = input.split_once("\n\n").unwrap();
-    let mut good_ranges = RangeStorage::new();
-    for line in ranges.lines() {
-        let (start, end) = line.split_once("-").unwrap();
-        good_ranges.add(
-            u64::from_str_radix(start, 10).unwrap(),
-            u64::from_str_radix(end, 10).unwrap()
-        );
-    }
+    let good_ranges = parse_ranges(ranges);
 
     let mut result = 0;
     for line in ids.lines() {
         let value = u64::from_str_radix(line, 10).unwrap();
-        if good_ranges.is_inside(value) {
+        if good_ranges.contains(value) {
             result += 1;
         }
     }
@@ -84,36 +26,73 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 pub fn part_two(input: &str) -> Option<u64> {
     let (ranges, _) = input.split_once("\n\n").unwrap();
-    let mut good_ranges = RangeStorage::new();
-    for line in ranges.lines() {
-        let (start, end) = line.split_once("-").unwrap();
-        good_ranges.add(
-            u64::from_str_radix(start, 10).unwrap(),
-            u64::from_str_radix(end, 10).unwrap()
-        );
-    }
-
-    good_ranges.optimise();
-    return Some(
-        good_ranges.ranges.iter()
-            .map(|range|{range.end-range.start+1}) // calculate length of each range
-            .sum()
-    );
+    let good_ranges = parse_ranges(ranges);
+    return Some(good_ranges.len() as u64);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    advent_of_code::solution_tests! {
+        part_one {
+            example => Some(3),
+        }
+        part_two {
+            example => Some(14),
+        }
+    }
+
+    use std::collections::HashSet;
+
+    use advent_of_code::template::property::{self, Rng};
+
+    fn generate(rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..rng.range(1..=6) {
+            let start = rng.range(1..=60);
+            input += &format!("{start}-{}\n", start + rng.range(0..=15));
+        }
+        input += "\n";
+        for _ in 0..rng.range(1..=10) {
+            input += &format!("{}\n", rng.range(1..=80));
+        }
+        input
+    }
+
+    /// Parse the input, panicking on inputs that are not valid puzzle inputs.
+    fn parse(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
+        let (ranges, ids) = input.split_once("\n\n").unwrap();
+        let ranges: Vec<(u64, u64)> = ranges
+            .lines()
+            .map(|line| {
+                let (start, end) = line.split_once('-').unwrap();
+                let range = (start.parse().unwrap(), end.parse().unwrap());
+                assert!(range.0 <= range.1);
+                range
+            })
+            .collect();
+        assert!(!ranges.is_empty());
+        (ranges, ids.lines().map(|id| id.parse().unwrap()).collect())
+    }
+
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+    fn part_one_matches_brute_force() {
+        property::check(generate, part_one, |input| {
+            let (ranges, ids) = parse(input);
+            let fresh = ids.iter().filter(|id| {
+                ranges.iter().any(|(start, end)| (start..=end).contains(id))
+            });
+            Some(fresh.count() as u64)
+        });
     }
 
     #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
+    fn part_two_matches_brute_force() {
+        property::check(generate, part_two, |input| {
+            let (ranges, _) = parse(input);
+            let fresh: HashSet<u64> = ranges.iter().flat_map(|(start, end)| *start..=*end).collect();
+            Some(fresh.len() as u64)
+        });
     }
 }
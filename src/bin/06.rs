@@ -73,15 +73,12 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
+    advent_of_code::solution_tests! {
+        part_one {
+            example => Some(4277556),
+        }
+        part_two {
+            example => Some(3263827),
+        }
     }
 }
@@ -1,5 +1,7 @@
 advent_of_code::solution!(12);
 
+use advent_of_code::grid::Grid;
+
 enum Rotation {
     R0,
     R90,
@@ -7,20 +9,21 @@ enum Rotation {
     R270,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct Box {
-    data: [[bool; 3]; 3],
+    rotations: [Grid<bool>; 4],
 }
 
 impl Box {
     fn at(&self, rot: &Rotation, x: usize, y: usize) -> bool {
         use Rotation::*;
-        match rot {
-            R0   => self.data[y][x],
-            R90  => self.data[2-x][y],
-            R180 => self.data[2-y][2-x],
-            R270 => self.data[x][2-y],
-        }
+        let rotated = match rot {
+            R0   => &self.rotations[0],
+            R90  => &self.rotations[1],
+            R180 => &self.rotations[2],
+            R270 => &self.rotations[3],
+        };
+        return rotated[(x, y)];
     }
 
     fn try_place(&self, rot: &Rotation, x_offset: isize, y_offset: isize, field: &Field) -> Option<Field> {
@@ -30,9 +33,7 @@ impl Box {
                 if self.at(rot, x, y) {
                     let point_x = x_offset + x as isize - 1;
                     let point_y = y_offset + y as isize - 1;
-                    if point_x < 0 || point_x >= field.size.0 as isize {return None;}
-                    if point_y < 0 || point_y >= field.size.1 as isize {return None;}
-                    if field.at(point_x as usize, point_y as usize) {return None;}
+                    if *field.fill.get_signed(point_x, point_y)? {return None;}
                     new_field.add_point(point_x as usize, point_y as usize);
                 }
             }
@@ -41,18 +42,12 @@ impl Box {
     }
 
     fn max_size(&self) -> usize {
-        self.data.iter().map(|line|{
-            line.iter().map(|&val|{if val {1} else {0}}).sum::<usize>()
-        }).sum()
+        self.rotations[0].iter().filter(|(_, val)| **val).count()
     }
 
-    fn new(data: Vec<Vec<bool>>) -> Self {
+    fn new(data: Grid<bool>) -> Self {
         Self {
-            data: [
-                [data[0][0], data[0][1], data[0][2]],
-                [data[1][0], data[1][1], data[1][2]],
-                [data[2][0], data[2][1], data[2][2]],
-            ]
+            rotations: [data.clone(), data.rotated_cw(), data.rotated_180(), data.rotated_ccw()],
         }
     }
 }
@@ -63,7 +58,7 @@ type BoxesCount = Vec<usize>;
 #[derive(Clone)]
 struct Field {
     size: (usize, usize),
-    fill: Vec<Vec<bool>>,
+    fill: Grid<bool>,
 }
 
 impl Field {
@@ -83,7 +78,7 @@ impl Field {
         for (box_type, &box_count) in boxes_count.iter().enumerate() {
             if box_count > 0 {
                 loop {
-                    let next_field_option = self.place(boxes[box_type], i);
+                    let next_field_option = self.place(&boxes[box_type], i);
                     i += 1;
                     match next_field_option {
                         Some(next_field) => {
@@ -105,7 +100,7 @@ impl Field {
         return Some(self.clone());
     }
 
-    fn place(&self, box_type: Box, skip_n: usize) -> Option<Self> {
+    fn place(&self, box_type: &Box, skip_n: usize) -> Option<Self> {
         let mut current_n = 0usize;
         for y in -1isize..=self.size.1 as isize {
             for x in -1isize..=self.size.0 as isize {
@@ -123,18 +118,14 @@ impl Field {
         return None;
     }
 
-    fn at(&self, x: usize, y: usize) -> bool {
-        return self.fill[y][x];
-    }
-
     fn add_point(&mut self, x: usize, y: usize) {
-        self.fill[y][x] = true;
+        self.fill[(x, y)] = true;
     }
 
     fn empty(size: (usize, usize)) -> Self {
         Self {
             size,
-            fill: vec![vec![false; size.0];size.1],
+            fill: Grid::filled(size.0, size.1, false),
         }
     }
 }
@@ -142,14 +133,8 @@ impl Field {
 pub fn part_one(input: &str) -> Option<u64> {
     let input_things: Vec<&str> = input.split("\n\n").collect();
     let boxes: Boxes = input_things[0..6].iter().map(|box_input|{
-        let box_lines: Vec<&str> = box_input.lines().collect();
-        Box::new(
-            vec![
-                box_lines[1].chars().map(|c|if c == '#' {true} else {false}).collect(),
-                box_lines[2].chars().map(|c|if c == '#' {true} else {false}).collect(),
-                box_lines[3].chars().map(|c|if c == '#' {true} else {false}).collect(),
-            ]
-        )
+        let (_index, shape) = box_input.split_once('\n').unwrap();
+        Box::new(Grid::parse(shape, |c| c == '#').unwrap())
     }).collect();
     let (fields_inputs, fields_boxes_inputs): (Vec<&str>, Vec<&str>) = input_things[input_things.len()-1].lines().collect::<Vec<_>>().iter().map(|line|{
         line.split_once(": ").unwrap()
@@ -0,0 +1,122 @@
+//! Coordinate compression: map a few interesting values from a huge range onto small indices.
+//!
+//! The values become cells `0, 2, 4, …` and the gaps between neighbouring values become cells
+//! `1, 3, 5, …`, so every value between the smallest and the largest falls into exactly one cell.
+//!
+//! ```
+//! # use advent_of_code::compress::Compression;
+//! let xs = Compression::new([98_000u64, 7, 1_000, 7]);
+//! assert_eq!(xs.len(), 3);
+//! assert_eq!(xs.cell(1_000), Some(2));
+//! assert_eq!(xs.cell(50_000), Some(3));
+//! assert_eq!(xs.cell_len(3), 96_999);
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::interval::Endpoint;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compression<T> {
+    /// The distinct values, ascending.
+    values: Vec<T>,
+}
+
+impl<T: Endpoint> Compression<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values: Vec<T> = values.into_iter().collect();
+        values.sort();
+        values.dedup();
+        Self { values }
+    }
+
+    /// The number of distinct values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The index of `value` among the distinct values, if it is one of them.
+    pub fn index(&self, value: T) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    pub fn value(&self, index: usize) -> T {
+        self.values[index]
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The number of cells, values and gaps together.
+    pub fn cell_count(&self) -> usize {
+        (2 * self.values.len()).saturating_sub(1)
+    }
+
+    /// The cell containing `value`, or `None` outside of the smallest and the largest value.
+    pub fn cell(&self, value: T) -> Option<usize> {
+        match self.values.binary_search(&value) {
+            Ok(index) => Some(2 * index),
+            Err(index) if index > 0 && index < self.values.len() => Some(2 * index - 1),
+            Err(_) => None,
+        }
+    }
+
+    /// The values in `cell`, empty for the gap between two neighbouring values.
+    pub fn cell_range(&self, cell: usize) -> RangeInclusive<T> {
+        let value = self.values[cell / 2];
+        if cell.is_multiple_of(2) {
+            value..=value
+        } else {
+            // neither can overflow: a larger value follows and a smaller one precedes.
+            value.succ().unwrap()..=self.values[cell / 2 + 1].pred().unwrap()
+        }
+    }
+
+    /// The number of values in `cell`.
+    pub fn cell_len(&self, cell: usize) -> u128 {
+        let range = self.cell_range(cell);
+        if range.is_empty() {
+            0
+        } else {
+            T::count(*range.start(), *range.end())
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Compression;
+
+    #[test]
+    fn compresses_values() {
+        let xs = Compression::new([5i64, -3, 10, 5, 6]);
+        assert_eq!(xs.values(), [-3, 5, 6, 10]);
+        assert_eq!((xs.index(6), xs.index(7)), (Some(2), None));
+        assert_eq!(xs.cell_count(), 7);
+
+        let cells: Vec<Option<usize>> = (-4..=11).map(|x| xs.cell(x)).collect();
+        assert_eq!(cells[0], None);
+        assert_eq!(
+            cells[1..=14],
+            [0, 1, 1, 1, 1, 1, 1, 1, 2, 4, 5, 5, 5, 6].map(Some)
+        );
+        assert_eq!(cells[15], None);
+    }
+
+    #[test]
+    fn measures_cells() {
+        let xs = Compression::new([-3i64, 5, 6, 10]);
+        let lengths: Vec<u128> = (0..xs.cell_count()).map(|cell| xs.cell_len(cell)).collect();
+        assert_eq!(lengths, [1, 7, 1, 0, 1, 3, 1]);
+        assert_eq!(lengths.iter().sum::<u128>(), 14);
+        assert_eq!(xs.cell_range(5), 7..=9);
+        assert!(xs.cell_range(3).is_empty());
+    }
+}
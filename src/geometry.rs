@@ -0,0 +1,698 @@
+//! Integer points, axis-aligned boxes and rectilinear polygons.
+//!
+//! Boxes and polygons describe tiles: a rectangle with corners `(2, 3)` and `(4, 5)` covers
+//! the 3×3 tiles between them, edges included.
+//!
+//! ```
+//! # use advent_of_code::geometry::{Point2, Rect, RectilinearPolygon};
+//! let polygon = RectilinearPolygon::new(
+//!     [(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)].map(Point2::from).to_vec(),
+//! )
+//! .unwrap();
+//! assert!(polygon.contains(Point2::new(8, 4)));
+//! assert!(polygon.contains_rect(&Rect::from_corners(Point2::new(9, 5), Point2::new(2, 3))));
+//! assert!(!polygon.contains_rect(&Rect::from_corners(Point2::new(2, 5), Point2::new(11, 1))));
+//! ```
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+use crate::{
+    compress::Compression,
+    interval::{Endpoint, IntervalSet},
+    summed_area::SummedAreaTable,
+};
+
+/// Integer types usable as coordinates.
+pub trait Coord:
+    Endpoint + Default + Hash + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    fn one() -> Self;
+
+    /// The absolute difference, in the same type so it works for unsigned coordinates.
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn to_i128(self) -> i128;
+
+    /// Panics if `value` does not fit.
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                fn one() -> Self {
+                    1
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    Self::try_from(value).expect("value out of range for the coordinate type")
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+/// An error found when building a [`RectilinearPolygon`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    TooFewVertices(usize),
+    /// The edge leaving vertex `index` is neither horizontal nor vertical.
+    Diagonal {
+        index: usize,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooFewVertices(found) => {
+                write!(f, "a polygon needs at least 4 vertices, found {found}")
+            }
+            Self::Diagonal { index } => {
+                write!(f, "the edge leaving vertex {index} is not axis-aligned")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The squared euclidean distance, which orders points like the distance itself.
+    pub fn distance_squared(&self, other: &Self) -> T {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        dx * dx + dy * dy
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coord> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Coord> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The squared euclidean distance, which orders points like the distance itself.
+    pub fn distance_squared(&self, other: &Self) -> T {
+        let (dx, dy, dz) = (
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        );
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Scales all coordinates.
+impl<T: Coord> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An axis-aligned rectangle of tiles, `min` and `max` included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coord> Rect<T> {
+    /// The rectangle with two opposite corners `a` and `b`, in any order.
+    pub fn from_corners(a: Point2<T>, b: Point2<T>) -> Self {
+        Self {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// The smallest rectangle containing all `points`, or `None` if there are none.
+    pub fn bounding(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, point| {
+            Some(match bounds {
+                None => Self::from_corners(point, point),
+                Some(Self { min, max }) => Self::from_corners(
+                    Point2::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point2::new(max.x.max(point.x), max.y.max(point.y)),
+                ),
+            })
+        })
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::one()
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::one()
+    }
+
+    /// The number of tiles covered.
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+/// An axis-aligned box of unit cubes, `min` and `max` included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Coord> Cuboid<T> {
+    /// The box with two opposite corners `a` and `b`, in any order.
+    pub fn from_corners(a: Point3<T>, b: Point3<T>) -> Self {
+        Self {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// The smallest box containing all `points`, or `None` if there are none.
+    pub fn bounding(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, point| {
+            Some(match bounds {
+                None => Self::from_corners(point, point),
+                Some(Self { min, max }) => Self::from_corners(
+                    Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+                    Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+                ),
+            })
+        })
+    }
+
+    /// The number of unit cubes covered.
+    pub fn volume(&self) -> T {
+        let size = self.max - self.min + Point3::new(T::one(), T::one(), T::one());
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, point: Point3<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A simple polygon whose edges are all horizontal or vertical, covering the tiles on and inside its boundary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RectilinearPolygon<T> {
+    /// The corners in order, the last one connects back to the first.
+    vertices: Vec<Point2<T>>,
+}
+
+impl<T: Coord> RectilinearPolygon<T> {
+    /// `vertices` are the corners in order, clockwise or counter-clockwise.
+    /// Every vertex must share a row or column with the next one.
+    pub fn new(vertices: Vec<Point2<T>>) -> Result<Self, Error> {
+        if vertices.len() < 4 {
+            return Err(Error::TooFewVertices(vertices.len()));
+        }
+        let polygon = Self { vertices };
+        if let Some(index) = polygon.edges().position(|(a, b)| a.x != b.x && a.y != b.y) {
+            return Err(Error::Diagonal { index });
+        }
+        Ok(polygon)
+    }
+
+    pub fn vertices(&self) -> &[Point2<T>] {
+        &self.vertices
+    }
+
+    /// Every edge as a pair of consecutive vertices, including the closing one.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    pub fn bounds(&self) -> Rect<T> {
+        Rect::bounding(self.vertices.iter().copied()).unwrap()
+    }
+
+    /// The total length of the edges.
+    pub fn perimeter(&self) -> T {
+        self.edges()
+            .fold(T::zero(), |length, (a, b)| length + a.manhattan(&b))
+    }
+
+    /// The area enclosed by the edges as lines through tile centres, by the shoelace formula.
+    pub fn shoelace_area(&self) -> T {
+        let twice: i128 = self
+            .edges()
+            .map(|(a, b)| a.x.to_i128() * b.y.to_i128() - b.x.to_i128() * a.y.to_i128())
+            .sum();
+        T::from_i128(twice.abs() / 2)
+    }
+
+    /// The number of tiles on or inside the boundary, by Pick's theorem.
+    pub fn area(&self) -> T {
+        let boundary = self.perimeter().to_i128();
+        T::from_i128(self.shoelace_area().to_i128() + boundary / 2 + 1)
+    }
+
+    /// The tiles of row `y` on or inside the boundary.
+    pub fn row(&self, y: T) -> IntervalSet<T> {
+        self.line(y, |point| (point.x, point.y))
+    }
+
+    /// The tiles of column `x` on or inside the boundary.
+    pub fn column(&self, x: T) -> IntervalSet<T> {
+        self.line(x, |point| (point.y, point.x))
+    }
+
+    /// The tiles of the line at `across` where `axes` maps points to `(along, across)`.
+    fn line(&self, across: T, axes: impl Fn(Point2<T>) -> (T, T)) -> IntervalSet<T> {
+        let mut tiles = IntervalSet::new();
+        let mut crossings = vec![];
+        for (a, b) in self.edges() {
+            let ((a_along, a_across), (b_along, b_across)) = (axes(a), axes(b));
+            let (low, high) = (a_across.min(b_across), a_across.max(b_across));
+            if a_along == b_along {
+                // an edge across the line touches it in one tile, and is crossed when the
+                // line passes its lower end: half-open, so each vertex is counted once.
+                if (low..=high).contains(&across) {
+                    tiles.insert(a_along..=a_along);
+                }
+                if low <= across && across < high {
+                    crossings.push(a_along);
+                }
+            } else if a_across == across {
+                tiles.insert(a_along.min(b_along)..=a_along.max(b_along));
+            }
+        }
+        crossings.sort();
+        for pair in crossings.chunks_exact(2) {
+            tiles.insert(pair[0]..=pair[1]);
+        }
+        tiles
+    }
+
+    /// Whether `point` is on or inside the boundary.
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        self.row(point.y).contains(point.x)
+    }
+
+    /// Whether every tile of `rect` is on or inside the boundary.
+    ///
+    /// Checking the rectangle's border is not enough: outside space can reach into it through a
+    /// zero-width channel between two touching edges. Rows only change at vertex rows, so every
+    /// vertex row in `rect` and the row after it are checked, which covers each distinct row once.
+    pub fn contains_rect(&self, rect: &Rect<T>) -> bool {
+        let mut rows = vec![rect.min.y];
+        for y in self.vertices.iter().map(|point| point.y) {
+            if (rect.min.y..=rect.max.y).contains(&y) {
+                rows.push(y);
+                rows.extend(y.succ().filter(|&next| next <= rect.max.y));
+            }
+        }
+        rows.sort();
+        rows.dedup();
+        rows.into_iter().all(|y| {
+            self.row(y)
+                .range_of(rect.min.x)
+                .is_some_and(|range| *range.end() >= rect.max.x)
+        })
+    }
+
+    /// Prepare to answer [`contains_rect`](Self::contains_rect) for many rectangles.
+    pub fn rect_index(&self) -> RectIndex<T> {
+        let xs = Compression::new(self.vertices.iter().map(|point| point.x));
+        let ys = Compression::new(self.vertices.iter().map(|point| point.y));
+        let (width, height) = (xs.cell_count(), ys.cell_count());
+
+        // edges only run along vertex coordinates, so all tiles of a cell are either in or out.
+        // only whether a rectangle overlaps an outside cell matters, so each one counts once.
+        let mut outside = vec![0usize; width * height];
+        for cy in 0..height {
+            let rows = ys.cell_range(cy);
+            if rows.is_empty() {
+                continue;
+            }
+            let tiles = self.row(*rows.start());
+            for cx in 0..width {
+                let columns = xs.cell_range(cx);
+                if !columns.is_empty() && !tiles.contains(*columns.start()) {
+                    outside[cy * width + cx] = 1;
+                }
+            }
+        }
+
+        RectIndex {
+            outside: SummedAreaTable::from_fn(width, height, |cx, cy| outside[cy * width + cx]),
+            xs,
+            ys,
+        }
+    }
+}
+
+/// A [`RectilinearPolygon`] compressed to the cells between its corner coordinates,
+/// with a summed-area table counting the cells outside of it.
+#[derive(Clone, Debug)]
+pub struct RectIndex<T> {
+    xs: Compression<T>,
+    ys: Compression<T>,
+    outside: SummedAreaTable<usize>,
+}
+
+impl<T: Coord> RectIndex<T> {
+    /// Whether every tile of `rect` is on or inside the boundary of the polygon.
+    /// Looks up the corners by binary search, then counts the outside cells in constant time.
+    pub fn contains_rect(&self, rect: &Rect<T>) -> bool {
+        let cells = (
+            self.xs.cell(rect.min.x),
+            self.ys.cell(rect.min.y),
+            self.xs.cell(rect.max.x),
+            self.ys.cell(rect.max.y),
+        );
+        match cells {
+            (Some(x1), Some(y1), Some(x2), Some(y2)) => self.outside.sum((x1, y1), (x2, y2)) == 0,
+            _ => false,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Cuboid, Error, Point2, Point3, Rect, RectilinearPolygon};
+
+    fn example() -> RectilinearPolygon<u64> {
+        let corners = [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ];
+        RectilinearPolygon::new(corners.map(Point2::from).to_vec()).unwrap()
+    }
+
+    #[test]
+    fn measures_points() {
+        let (a, b) = (Point3::new(162u64, 817, 812), Point3::new(425, 690, 689));
+        assert_eq!(a.distance_squared(&b), 263 * 263 + 127 * 127 + 123 * 123);
+        assert_eq!(a.manhattan(&b), 263 + 127 + 123);
+        assert_eq!(
+            Point2::new(-1i32, 4) + Point2::new(3, -6) * 2,
+            Point2::new(5, -8)
+        );
+        assert_eq!(Point2::new(1u8, 2).to_string(), "1,2");
+    }
+
+    #[test]
+    fn bounds_points() {
+        let rect = Rect::bounding([(2, 5), (11, 1), (7, 3)].map(Point2::<u64>::from)).unwrap();
+        assert_eq!(
+            (rect.min, rect.max),
+            (Point2::new(2, 1), Point2::new(11, 5))
+        );
+        assert_eq!(rect.area(), 50);
+        assert!(rect.contains(Point2::new(11, 5)) && !rect.contains(Point2::new(12, 5)));
+        assert_eq!(Rect::<u64>::bounding([]), None);
+
+        let cuboid = Cuboid::bounding([(0, 0, 0), (1, -2, 3)].map(Point3::<i64>::from)).unwrap();
+        assert_eq!(cuboid.volume(), 2 * 3 * 4);
+        assert!(cuboid.contains(Point3::new(1, -1, 0)));
+    }
+
+    #[test]
+    fn measures_polygons() {
+        let polygon = example();
+        assert_eq!(polygon.perimeter(), 30);
+        assert_eq!(polygon.shoelace_area(), 30);
+        assert_eq!(polygon.area(), 46);
+        assert_eq!(
+            polygon.bounds(),
+            Rect::from_corners(Point2::new(2, 1), Point2::new(11, 7))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_polygons() {
+        let diagonal = [(0, 0), (2, 0), (2, 2), (1, 3)]
+            .map(Point2::<i32>::from)
+            .to_vec();
+        assert_eq!(
+            RectilinearPolygon::new(diagonal),
+            Err(Error::Diagonal { index: 2 })
+        );
+        assert_eq!(
+            RectilinearPolygon::<i32>::new(vec![]),
+            Err(Error::TooFewVertices(0))
+        );
+    }
+
+    #[test]
+    fn contains_points_and_rectangles() {
+        let polygon = example();
+        let inside: usize = (0..13)
+            .flat_map(|y| (0..13).map(move |x| Point2::new(x, y)))
+            .filter(|&point| polygon.contains(point))
+            .count();
+        assert_eq!(inside as u64, polygon.area());
+        assert_eq!(polygon.row(5).iter().collect::<Vec<_>>(), [2..=11]);
+        assert_eq!(polygon.column(8).iter().collect::<Vec<_>>(), [1..=5]);
+
+        let rect = |a: (u64, u64), b: (u64, u64)| Rect::from_corners(a.into(), b.into());
+        assert!(polygon.contains_rect(&rect((7, 3), (11, 1))));
+        assert!(polygon.contains_rect(&rect((9, 7), (11, 5))));
+        assert!(!polygon.contains_rect(&rect((2, 3), (11, 7))));
+        assert!(!polygon.contains_rect(&rect((2, 1), (7, 3))));
+        assert!(!polygon.contains_rect(&rect((0, 0), (3, 3))));
+    }
+
+    /// Outside space enters between `(4,0)-(4,3)` and `(5,3)-(5,0)`, so the border of the
+    /// bounding box is covered but its middle is not.
+    const CHANNEL: [(u64, u64); 12] = [
+        (0, 0),
+        (4, 0),
+        (4, 3),
+        (2, 3),
+        (2, 7),
+        (8, 7),
+        (8, 3),
+        (5, 3),
+        (5, 0),
+        (10, 0),
+        (10, 10),
+        (0, 10),
+    ];
+
+    /// A U whose arms touch, so the gap between them is all boundary tiles.
+    const TOUCHING: [(u64, u64); 8] = [
+        (0, 0),
+        (2, 0),
+        (2, 2),
+        (3, 2),
+        (3, 0),
+        (5, 0),
+        (5, 4),
+        (0, 4),
+    ];
+
+    fn polygon(corners: &[(u64, u64)]) -> RectilinearPolygon<u64> {
+        RectilinearPolygon::new(corners.iter().map(|&corner| corner.into()).collect()).unwrap()
+    }
+
+    /// Every rectangle within `size × size`, with whether all of its tiles are in `polygon`.
+    fn rectangles_by_tiles(polygon: &RectilinearPolygon<u64>, size: u64) -> Vec<(Rect<u64>, bool)> {
+        let inside: Vec<Vec<bool>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| polygon.contains(Point2::new(x, y)))
+                    .collect()
+            })
+            .collect();
+        let spans = move || (0..size).flat_map(move |a| (a..size).map(move |b| (a, b)));
+        spans()
+            .flat_map(|(x1, x2)| spans().map(move |(y1, y2)| (x1, x2, y1, y2)))
+            .map(|(x1, x2, y1, y2)| {
+                let rect = Rect::from_corners(Point2::new(x1, y1), Point2::new(x2, y2));
+                let covered = (y1..=y2).all(|y| (x1..=x2).all(|x| inside[y as usize][x as usize]));
+                (rect, covered)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rejects_rectangles_over_zero_width_channels() {
+        let polygon = polygon(&CHANNEL);
+        let whole = Rect::from_corners(Point2::new(0, 0), Point2::new(10, 10));
+        assert!(!polygon.contains(Point2::new(5, 5)));
+        assert!(!polygon.contains_rect(&whole));
+        assert_eq!(polygon.area(), 106);
+
+        for (rect, covered) in rectangles_by_tiles(&polygon, 11) {
+            assert_eq!(polygon.contains_rect(&rect), covered, "{rect:?}");
+        }
+    }
+
+    #[test]
+    fn contains_rectangles_across_touching_edges() {
+        let polygon = polygon(&TOUCHING);
+        let whole = Rect::from_corners(Point2::new(0, 0), Point2::new(5, 4));
+        assert!(polygon.contains_rect(&whole));
+        assert!(polygon.rect_index().contains_rect(&whole));
+        assert_eq!(polygon.area(), 30);
+    }
+
+    #[test]
+    fn indexes_full_width_coordinates() {
+        // an L along two edges of the i128 plane, the outside cell in between has about 2^256 tiles.
+        let (min, max) = (i128::MIN, i128::MAX);
+        let corners = [
+            (min, min),
+            (max, min),
+            (max, min + 1),
+            (min + 1, min + 1),
+            (min + 1, max),
+            (min, max),
+        ];
+        let polygon = RectilinearPolygon::new(corners.map(Point2::from).to_vec()).unwrap();
+        let index = polygon.rect_index();
+        let rect = |a: (i128, i128), b: (i128, i128)| Rect::from_corners(a.into(), b.into());
+        assert!(index.contains_rect(&rect((min, min), (max, min + 1))));
+        assert!(index.contains_rect(&rect((min, min), (min + 1, max))));
+        assert!(!index.contains_rect(&rect((min, min), (max, max))));
+        assert!(!index.contains_rect(&rect((0, 0), (1, 1))));
+    }
+
+    #[test]
+    fn indexes_rectangles() {
+        let polygons = [example(), polygon(&CHANNEL), polygon(&TOUCHING)];
+        for polygon in &polygons {
+            let index = polygon.rect_index();
+            for (rect, covered) in rectangles_by_tiles(polygon, 13) {
+                assert_eq!(
+                    index.contains_rect(&rect),
+                    covered,
+                    "{rect:?} in {polygon:?}"
+                );
+            }
+        }
+    }
+}
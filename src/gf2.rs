@@ -0,0 +1,390 @@
+//! Linear algebra over GF(2), the field of bits with XOR as addition.
+//!
+//! "Lights out" puzzles are systems of such equations: every button toggles a set of lights,
+//! and pressing a button twice cancels out.
+//!
+//! ```
+//! # use advent_of_code::gf2::{BitVector, System};
+//! // lights .##. with buttons (3) (1,3) (2) (2,3) (0,2) (0,1)
+//! let buttons: Vec<BitVector> = [&[3][..], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]]
+//!     .iter()
+//!     .map(|lights| BitVector::from_indices(4, lights))
+//!     .collect();
+//! let target = BitVector::from_indices(4, &[1, 2]);
+//! let presses = System::from_columns(&buttons, &target).min_weight_solution().unwrap();
+//! assert_eq!(presses.count_ones(), 2);
+//! ```
+
+use std::{
+    fmt::Display,
+    ops::{BitXor, BitXorAssign},
+};
+
+/// A fixed-length vector of bits, packed into 64-bit words.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitVector {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVector {
+    /// A vector of `len` zero bits.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// A vector of `len` bits where the bits at `indices` are set.
+    pub fn from_indices(len: usize, indices: &[usize]) -> Self {
+        let mut vector = Self::zeros(len);
+        for &i in indices {
+            vector.set(i, true);
+        }
+        vector
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(
+            i < self.len,
+            "bit {i} is out of range for length {}",
+            self.len
+        );
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(
+            i < self.len,
+            "bit {i} is out of range for length {}",
+            self.len
+        );
+        if value {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    pub fn flip(&mut self, i: usize) {
+        let value = self.get(i);
+        self.set(i, !value);
+    }
+
+    /// The number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// The indices of the set bits, ascending.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    i * 64 + bit
+                })
+            })
+        })
+    }
+
+    /// The index of the first set bit.
+    pub fn first_one(&self) -> Option<usize> {
+        self.ones().next()
+    }
+}
+
+impl BitXorAssign<&BitVector> for BitVector {
+    fn bitxor_assign(&mut self, rhs: &BitVector) {
+        assert_eq!(self.len, rhs.len, "bit vectors must have the same length");
+        for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+            *a ^= b;
+        }
+    }
+}
+
+impl BitXor<&BitVector> for &BitVector {
+    type Output = BitVector;
+
+    fn bitxor(self, rhs: &BitVector) -> BitVector {
+        let mut result = self.clone();
+        result ^= rhs;
+        result
+    }
+}
+
+/// Prints the bits as `0` and `1`, first bit first.
+impl Display for BitVector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.len {
+            write!(f, "{}", u8::from(self.get(i)))?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A system of linear equations `A·x = b` over GF(2), reduced by Gaussian elimination.
+#[derive(Clone, Debug)]
+pub struct System {
+    /// Rows of the reduced matrix, each with its right-hand side.
+    rows: Vec<(BitVector, bool)>,
+    /// The pivot variable of every row.
+    pivots: Vec<usize>,
+    variables: usize,
+    consistent: bool,
+}
+
+impl System {
+    /// A system over `variables` unknowns with one row per equation,
+    /// `rhs[i]` is the right-hand side of `rows[i]`.
+    pub fn from_rows(variables: usize, rows: &[BitVector], rhs: &BitVector) -> Self {
+        assert_eq!(
+            rows.len(),
+            rhs.len(),
+            "every equation needs a right-hand side"
+        );
+        assert!(
+            rows.iter().all(|row| row.len() == variables),
+            "every equation needs a coefficient for each of the {variables} variables"
+        );
+        let mut system = Self {
+            rows: rows
+                .iter()
+                .cloned()
+                .zip((0..rhs.len()).map(|i| rhs.get(i)))
+                .collect(),
+            pivots: vec![],
+            variables,
+            consistent: true,
+        };
+        system.eliminate();
+        system
+    }
+
+    /// One column per variable, e.g. the lights toggled by each button, and the target as right-hand side.
+    pub fn from_columns(columns: &[BitVector], target: &BitVector) -> Self {
+        assert!(
+            columns.iter().all(|column| column.len() == target.len()),
+            "every column needs a bit for each of the {} equations",
+            target.len()
+        );
+        let rows: Vec<BitVector> = (0..target.len())
+            .map(|row| {
+                let mut vector = BitVector::zeros(columns.len());
+                for (variable, column) in columns.iter().enumerate() {
+                    vector.set(variable, column.get(row));
+                }
+                vector
+            })
+            .collect();
+        Self::from_rows(columns.len(), &rows, target)
+    }
+
+    /// Bring the rows into reduced row echelon form.
+    fn eliminate(&mut self) {
+        let mut rank = 0;
+        for variable in 0..self.variables {
+            let Some(pivot) = (rank..self.rows.len()).find(|&r| self.rows[r].0.get(variable))
+            else {
+                continue;
+            };
+            self.rows.swap(rank, pivot);
+            let (pivot_row, pivot_rhs) = self.rows[rank].clone();
+            for (r, (row, rhs)) in self.rows.iter_mut().enumerate() {
+                if r != rank && row.get(variable) {
+                    *row ^= &pivot_row;
+                    *rhs ^= pivot_rhs;
+                }
+            }
+            self.pivots.push(variable);
+            rank += 1;
+        }
+
+        // the remaining rows are zero, so they read `0 = rhs`.
+        self.consistent = self.rows[rank..].iter().all(|(_, rhs)| !rhs);
+        self.rows.truncate(rank);
+    }
+
+    /// Whether the system has any solution.
+    pub fn is_consistent(&self) -> bool {
+        self.consistent
+    }
+
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// The variables that can be chosen freely, ascending.
+    pub fn free_variables(&self) -> Vec<usize> {
+        (0..self.variables)
+            .filter(|v| !self.pivots.contains(v))
+            .collect()
+    }
+
+    /// The solution with all free variables set to zero.
+    pub fn particular(&self) -> Option<BitVector> {
+        if !self.consistent {
+            return None;
+        }
+        let mut solution = BitVector::zeros(self.variables);
+        for (&pivot, (_, rhs)) in self.pivots.iter().zip(&self.rows) {
+            solution.set(pivot, *rhs);
+        }
+        Some(solution)
+    }
+
+    /// A basis of the solutions of `A·x = 0`, one vector per free variable.
+    /// Adding any combination of it to a solution gives another solution.
+    pub fn null_space(&self) -> Vec<BitVector> {
+        self.free_variables()
+            .into_iter()
+            .map(|free| {
+                let mut vector = BitVector::zeros(self.variables);
+                vector.set(free, true);
+                for (&pivot, (row, _)) in self.pivots.iter().zip(&self.rows) {
+                    vector.set(pivot, row.get(free));
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// All `2^k` solutions, where `k` is the number of free variables.
+    pub fn solutions(&self) -> impl Iterator<Item = BitVector> {
+        let basis = self.null_space();
+        let mut current = self.particular();
+        let mut step: u64 = 0;
+        assert!(basis.len() < 64, "too many free variables to enumerate");
+
+        std::iter::from_fn(move || {
+            let solution = current.clone()?;
+            step += 1;
+            // gray code: every next combination differs in a single basis vector.
+            match basis.get(step.trailing_zeros() as usize) {
+                Some(vector) if step < 1 << basis.len() => {
+                    *current.as_mut().unwrap() ^= vector;
+                }
+                _ => current = None,
+            }
+            Some(solution)
+        })
+    }
+
+    /// The solution with the fewest set bits, e.g. the fewest button presses.
+    /// Enumerates the whole solution space, so it suits systems with few free variables.
+    pub fn min_weight_solution(&self) -> Option<BitVector> {
+        self.solutions().min_by_key(BitVector::count_ones)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BitVector, System};
+
+    fn bits(text: &str) -> BitVector {
+        let ones: Vec<usize> = text
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| *c == '1')
+            .map(|(i, _)| i)
+            .collect();
+        BitVector::from_indices(text.len(), &ones)
+    }
+
+    #[test]
+    fn packs_bits() {
+        let mut vector = BitVector::from_indices(130, &[0, 64, 129]);
+        assert_eq!(vector.count_ones(), 3);
+        assert_eq!(vector.ones().collect::<Vec<_>>(), [0, 64, 129]);
+        vector.flip(64);
+        assert!(!vector.get(64));
+        vector ^= &BitVector::from_indices(130, &[0, 129]);
+        assert!(vector.is_zero());
+        assert_eq!(bits("0110").to_string(), "0110");
+    }
+
+    #[test]
+    fn solves_systems() {
+        // x0 + x1 = 1, x1 + x2 = 0
+        let system = System::from_rows(3, &[bits("110"), bits("011")], &bits("10"));
+        assert!(system.is_consistent());
+        assert_eq!(system.rank(), 2);
+        assert_eq!(system.free_variables(), [2]);
+        assert_eq!(system.particular(), Some(bits("100")));
+        assert_eq!(system.null_space(), [bits("111")]);
+
+        let mut solutions: Vec<String> = system.solutions().map(|s| s.to_string()).collect();
+        solutions.sort();
+        assert_eq!(solutions, ["011", "100"]);
+    }
+
+    #[test]
+    fn detects_inconsistent_systems() {
+        let system = System::from_rows(2, &[bits("11"), bits("11")], &bits("10"));
+        assert!(!system.is_consistent());
+        assert_eq!(system.solutions().count(), 0);
+        assert_eq!(system.min_weight_solution(), None);
+    }
+
+    #[test]
+    fn solves_systems_without_equations() {
+        let buttons = [BitVector::zeros(0), BitVector::zeros(0)];
+        let system = System::from_columns(&buttons, &BitVector::zeros(0));
+        assert_eq!(system.free_variables(), [0, 1]);
+        assert_eq!(system.particular(), Some(bits("00")));
+        assert_eq!(system.min_weight_solution(), Some(bits("00")));
+        assert_eq!(system.solutions().count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "every column needs a bit for each of the 3 equations")]
+    fn rejects_columns_of_the_wrong_length() {
+        System::from_columns(&[bits("101"), bits("10")], &bits("001"));
+    }
+
+    #[test]
+    fn finds_minimum_weight_solutions() {
+        // lights ...#. with buttons (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4)
+        let buttons = [
+            BitVector::from_indices(5, &[0, 2, 3, 4]),
+            BitVector::from_indices(5, &[2, 3]),
+            BitVector::from_indices(5, &[0, 4]),
+            BitVector::from_indices(5, &[0, 1, 2]),
+            BitVector::from_indices(5, &[1, 2, 3, 4]),
+        ];
+        let system = System::from_columns(&buttons, &bits("00010"));
+        assert_eq!(system.min_weight_solution().unwrap().count_ones(), 3);
+
+        // every enumerated solution solves the system
+        for solution in system.solutions() {
+            let mut lights = BitVector::zeros(5);
+            for button in solution.ones() {
+                lights ^= &buttons[button];
+            }
+            assert_eq!(lights, bits("00010"));
+        }
+        assert_eq!(
+            system.solutions().count(),
+            1 << system.free_variables().len()
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! Directed graphs with named nodes, stored as adjacency lists of node indices.
+//!
+//! ```
+//! # use advent_of_code::graph::Graph;
+//! let graph = Graph::parse_adjacency("you: bbb ccc\nbbb: out\nccc: bbb out").unwrap();
+//! let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+//! assert_eq!(graph.count_paths(you, out), Some(3));
+//! assert_eq!(graph.bfs(you)[out], Some(2));
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+use crate::parse::{self, ErrorKind, Span};
+
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    /// Outgoing edges of every node as `(target, weight)`.
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse lines like `aaa: bbb ccc`, each listing the targets of the edges leaving a node.
+    /// Targets may be separated by whitespace or commas. All edges have weight 1.
+    pub fn parse_adjacency(input: &str) -> Result<Self, parse::Error> {
+        let mut graph = Self::new();
+        for line in Span::new(input).lines() {
+            let (name, targets) = line.split_once(":")?;
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(name.error(ErrorKind::Expected("a node name".into())));
+            }
+            let from = graph.node(name.as_str());
+            for target in targets.as_str().split([' ', ',', '\t']) {
+                if !target.is_empty() {
+                    let to = graph.node(target);
+                    graph.add_edge(from, to, 1);
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// The id of the node called `name`, adding it if it does not exist yet.
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.into());
+        self.ids.insert(name.into(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// The id of the node called `name`, if it exists.
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        self.edges[from].push((to, weight));
+    }
+
+    /// The outgoing edges of a node as `(target, weight)`.
+    pub fn edges(&self, id: usize) -> &[(usize, u64)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[id].iter().map(|&(to, _)| to)
+    }
+
+    /// The nodes ordered so that every edge points forward, or `None` if the graph has a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for to in (0..self.len()).flat_map(|id| self.neighbours(id)) {
+            incoming[to] += 1;
+        }
+
+        let mut ready: Vec<usize> = (0..self.len()).filter(|&id| incoming[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop() {
+            order.push(id);
+            for to in self.neighbours(id) {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push(to);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// A cycle as a list of nodes, each with an edge to the next and the last with an edge to the first.
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            OnStack,
+            Done,
+        }
+
+        let mut state = vec![State::Unvisited; self.len()];
+        for start in 0..self.len() {
+            if state[start] != State::Unvisited {
+                continue;
+            }
+            // iterative depth first search, the stack holds nodes and the index of their next edge.
+            let mut stack = vec![(start, 0)];
+            state[start] = State::OnStack;
+            while let Some((id, edge)) = stack.last_mut() {
+                let Some(&(to, _)) = self.edges[*id].get(*edge) else {
+                    state[*id] = State::Done;
+                    stack.pop();
+                    continue;
+                };
+                *edge += 1;
+                match state[to] {
+                    State::Unvisited => {
+                        state[to] = State::OnStack;
+                        stack.push((to, 0));
+                    }
+                    State::OnStack => {
+                        let from = stack.iter().position(|&(id, _)| id == to).unwrap();
+                        return Some(stack[from..].iter().map(|&(id, _)| id).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// The number of paths from `from` to `to`, or `None` if the graph has a cycle.
+    pub fn count_paths(&self, from: usize, to: usize) -> Option<u64> {
+        self.count_paths_through(from, to, &[])
+    }
+
+    /// The number of paths from `from` to `to` that visit every node of `waypoints`, in any order.
+    /// Returns `None` if the graph has a cycle.
+    pub fn count_paths_through(&self, from: usize, to: usize, waypoints: &[usize]) -> Option<u64> {
+        assert!(waypoints.len() < 16, "too many waypoints");
+        let order = self.topological_sort()?;
+
+        // bit `i` of a mask is set once `waypoints[i]` was visited.
+        let bit = |id: usize| {
+            waypoints
+                .iter()
+                .enumerate()
+                .filter(|&(_, &waypoint)| waypoint == id)
+                .fold(0usize, |mask, (i, _)| mask | 1 << i)
+        };
+        let masks = 1 << waypoints.len();
+        let mut paths = vec![0u64; self.len() * masks];
+        paths[from * masks + bit(from)] = 1;
+
+        for id in order {
+            for mask in 0..masks {
+                let count = paths[id * masks + mask];
+                if count == 0 {
+                    continue;
+                }
+                for to in self.neighbours(id) {
+                    paths[to * masks + (mask | bit(to))] += count;
+                }
+            }
+        }
+
+        Some(paths[to * masks + masks - 1])
+    }
+
+    /// The number of edges on the shortest path from `from` to every node, ignoring weights.
+    pub fn bfs(&self, from: usize) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.len()];
+        distances[from] = Some(0);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(id) = queue.pop_front() {
+            let distance = distances[id].unwrap();
+            for to in self.neighbours(id) {
+                if distances[to].is_none() {
+                    distances[to] = Some(distance + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The total weight of the lightest path from `from` to every node.
+    pub fn dijkstra(&self, from: usize) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, from))]);
+
+        while let Some(Reverse((distance, id))) = queue.pop() {
+            if distances[id].is_some() {
+                continue;
+            }
+            distances[id] = Some(distance);
+            for &(to, weight) in &self.edges[id] {
+                if distances[to].is_none() {
+                    queue.push(Reverse((distance + weight, to)));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The lightest path from `from` to `to` and its total weight.
+    /// `heuristic` estimates the remaining weight to `to` and must never overestimate it.
+    /// A node is searched again whenever a lighter path to it turns up, so the heuristic
+    /// does not need to be consistent.
+    pub fn a_star(
+        &self,
+        from: usize,
+        to: usize,
+        heuristic: impl Fn(usize) -> u64,
+    ) -> Option<(u64, Vec<usize>)> {
+        let mut distances: Vec<Option<u64>> = vec![None; self.len()];
+        let mut previous = vec![usize::MAX; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((heuristic(from), 0, from))]);
+        distances[from] = Some(0);
+
+        while let Some(Reverse((_, distance, id))) = queue.pop() {
+            // a lighter path to `id` was found after this entry was queued.
+            if distances[id].is_some_and(|known| known < distance) {
+                continue;
+            }
+            if id == to {
+                let mut path = vec![to];
+                while *path.last().unwrap() != from {
+                    path.push(previous[*path.last().unwrap()]);
+                }
+                path.reverse();
+                return Some((distance, path));
+            }
+            for &(next, weight) in &self.edges[id] {
+                let candidate = distance + weight;
+                if distances[next].is_none_or(|known| candidate < known) {
+                    distances[next] = Some(candidate);
+                    previous[next] = id;
+                    queue.push(Reverse((candidate + heuristic(next), candidate, next)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Graph;
+
+    fn example() -> Graph {
+        Graph::parse_adjacency(
+            "svr: aaa bbb\naaa: fft\nfft: ccc\nbbb: tty\ntty: ccc\nccc: ddd eee\nddd: hub\nhub: fff\neee: dac\ndac: fff\nfff: ggg hhh\nggg: out\nhhh: out",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn interns_names() {
+        let mut graph = example();
+        let out = graph.id("out").unwrap();
+        assert_eq!(graph.name(out), "out");
+        assert_eq!(graph.node("out"), out);
+        assert_eq!(graph.len(), 14);
+        assert_eq!(graph.id("nope"), None);
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let error = Graph::parse_adjacency("aaa: bbb\nccc ddd").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+    }
+
+    #[test]
+    fn counts_paths_through_waypoints() {
+        let graph = example();
+        let id = |name| graph.id(name).unwrap();
+        assert_eq!(graph.count_paths(id("svr"), id("out")), Some(8));
+        assert_eq!(
+            graph.count_paths_through(id("svr"), id("out"), &[id("fft"), id("dac")]),
+            Some(2)
+        );
+        assert_eq!(graph.count_paths(id("out"), id("svr")), Some(0));
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = example();
+        let order = graph.topological_sort().unwrap();
+        let position = |id| order.iter().position(|&x| x == id).unwrap();
+        for from in 0..graph.len() {
+            for to in graph.neighbours(from) {
+                assert!(position(from) < position(to));
+            }
+        }
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let graph = Graph::parse_adjacency("a: b\nb: c\nc: d a\nd:").unwrap();
+        let names: Vec<&str> = graph
+            .find_cycle()
+            .unwrap()
+            .into_iter()
+            .map(|id| graph.name(id))
+            .collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert_eq!(graph.topological_sort(), None);
+        assert_eq!(graph.count_paths(0, 3), None);
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let mut graph = Graph::new();
+        let [a, b, c, d] = ["a", "b", "c", "d"].map(|name| graph.node(name));
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, d, 10);
+        graph.add_edge(a, c, 4);
+        graph.add_edge(c, d, 2);
+
+        assert_eq!(graph.bfs(a), [Some(0), Some(1), Some(1), Some(2)]);
+        assert_eq!(graph.dijkstra(a), [Some(0), Some(1), Some(4), Some(6)]);
+        assert_eq!(graph.dijkstra(d), [None, None, None, Some(0)]);
+        assert_eq!(graph.a_star(a, d, |_| 0), Some((6, vec![a, c, d])));
+        assert_eq!(graph.a_star(d, a, |_| 0), None);
+    }
+
+    #[test]
+    fn searches_with_inconsistent_heuristics() {
+        let mut graph = Graph::new();
+        let [s, a, b, t] = ["s", "a", "b", "t"].map(|name| graph.node(name));
+        graph.add_edge(s, a, 4);
+        graph.add_edge(s, b, 1);
+        graph.add_edge(b, a, 1);
+        graph.add_edge(a, t, 4);
+
+        // admissible, but `b` looks worse than going through `a` directly, so `a` is
+        // searched first and has to be searched again once the path through `b` is found.
+        let heuristic = |id| if id == b { 5 } else { 0 };
+        assert_eq!(graph.a_star(s, t, heuristic), Some((6, vec![s, b, a, t])));
+    }
+}
@@ -0,0 +1,465 @@
+//! A rectangular 2D grid, as found in most map-based puzzles.
+//!
+//! Cells are addressed as `(x, y)`, with `x` the column and `y` the row, starting at the top left.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// Offsets of the orthogonal neighbours: up, right, down, left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise starting at up.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line of the character map has a different length than the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} of the grid has {found} cells, expected {expected}."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parse a character map, converting every character with `cell`.
+    ///
+    /// ```
+    /// # use advent_of_code::grid::Grid;
+    /// let grid = Grid::parse("#..\n.#.", |c| c == '#').unwrap();
+    /// assert_eq!((grid.width(), grid.height()), (3, 2));
+    /// assert_eq!(grid[(1, 1)], true);
+    /// ```
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let found = cells.len() - before;
+
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(Error::Ragged {
+                        line: y + 1,
+                        expected,
+                        found,
+                    });
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Create a grid from row-major cells. Panics if `cells` does not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width) || cells.is_empty(),
+            "{} cells do not fill rows of width {width}",
+            cells.len()
+        );
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Create a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.contains(x, y)
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The cell at signed coordinates, or `None` outside of the grid.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        self.get(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+
+    /// The cell at signed coordinates, wrapping around the edges as if the grid repeats infinitely.
+    /// Only `None` for an empty grid.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        self.get(x, y)
+    }
+
+    /// Positions of the cells next to `(x, y)` at the given offsets that lie inside the grid.
+    pub fn neighbours<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<'a, T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+
+    /// Positions of the orthogonal neighbours of `(x, y)`.
+    pub fn neighbours_4(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(x, y, &DIRECTIONS_4)
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of `(x, y)`.
+    pub fn neighbours_8(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(x, y, &DIRECTIONS_8)
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Convert every cell with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Build a grid of the given size from the cell of `self` that `source` maps each position to.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Swap rows and columns, i.e. mirror along the main diagonal.
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotate by 90 degrees clockwise.
+    pub fn rotated_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotate by 90 degrees counter-clockwise.
+    pub fn rotated_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Rotate by 180 degrees.
+    pub fn rotated_180(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y))
+    }
+
+    /// Mirror left to right.
+    pub fn flipped_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirror top to bottom.
+    pub fn flipped_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+
+    /// Render the grid as a character map, one line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the {width}x{height} grid"))
+    }
+}
+
+/// Prints the grid row by row, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Error, Grid};
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, |c| c).unwrap()
+    }
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = chars("abc\ndef\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn rejects_ragged_maps() {
+        assert_eq!(
+            Grid::parse("abc\nde\n", |c| c),
+            Err(Error::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = chars("ab\ncd");
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&'d'));
+        assert_eq!(grid.get_wrapping(-1, 2), Some(&'b'));
+        assert_eq!(Grid::filled(3, 0, 'a').get_wrapping(1, 0), None);
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = chars("abc\ndef\nghi");
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours_8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the 3x2 grid")]
+    fn rejects_columns_outside_of_the_grid() {
+        chars(
+            "abc
+def",
+        )
+        .column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is outside of the 3x2 grid")]
+    fn rejects_rows_outside_of_the_grid() {
+        chars(
+            "abc
+def",
+        )
+        .row(2);
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.rotated_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotated_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotated_180().to_string(), "fed\ncba\n");
+        assert_eq!(grid.flipped_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flipped_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotated_cw().rotated_ccw(), grid);
+    }
+
+    #[test]
+    fn renders_cells() {
+        let grid = Grid::parse("#.\n.#", |c| c == '#').unwrap();
+        assert_eq!(grid.render(|&on| if on { '█' } else { ' ' }), "█ \n █\n");
+    }
+}
@@ -0,0 +1,491 @@
+//! Integer linear programming: minimise a linear objective over non-negative integers
+//! subject to linear constraints.
+//!
+//! The linear relaxation is solved exactly with the simplex method,
+//! fractional solutions are split into two subproblems (branch and bound).
+//!
+//! ```
+//! # use advent_of_code::ilp::IntegerProgram;
+//! // fewest presses of buttons (0), (0,1) and (1) to reach joltages {3,5}
+//! let mut program = IntegerProgram::new(vec![1, 1, 1]);
+//! program.equal(vec![1, 1, 0], 3).equal(vec![0, 1, 1], 5);
+//! let solution = program.minimize().unwrap();
+//! assert_eq!(solution.value, 5);
+//! ```
+
+use std::fmt::Display;
+
+use crate::linalg::Rational;
+
+/// The number of subproblems [`IntegerProgram::minimize`] solves before giving up, unless changed with
+/// [`IntegerProgram::node_limit`].
+pub const DEFAULT_NODE_LIMIT: usize = 100_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Relation {
+    Equal,
+    AtMost,
+    AtLeast,
+}
+
+#[derive(Clone, Debug)]
+struct Constraint {
+    coefficients: Vec<i64>,
+    relation: Relation,
+    rhs: i64,
+}
+
+/// The optimum of the linear relaxation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Relaxation {
+    Optimal {
+        value: Rational,
+        variables: Vec<Rational>,
+    },
+    Infeasible,
+    /// The objective can decrease without limit.
+    Unbounded,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solution {
+    pub value: i64,
+    pub variables: Vec<i64>,
+}
+
+/// The reason [`IntegerProgram::minimize`] found no solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// There is no integer solution.
+    Infeasible,
+    /// The objective can decrease without limit.
+    Unbounded,
+    /// The search solved this many subproblems without proving an optimum,
+    /// e.g. because the relaxation keeps branching towards infinity.
+    NodeLimit(usize),
+    /// A value of the optimum does not fit into an `i64`.
+    Overflow,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Infeasible => write!(f, "the program has no integer solution"),
+            Self::Unbounded => write!(f, "the objective is unbounded"),
+            Self::NodeLimit(nodes) => {
+                write!(f, "gave up after solving {nodes} subproblems")
+            }
+            Self::Overflow => write!(f, "the optimum does not fit into an i64"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// `minimize objective·x` subject to the added constraints, with `x` non-negative integers.
+#[derive(Clone, Debug)]
+pub struct IntegerProgram {
+    objective: Vec<i64>,
+    constraints: Vec<Constraint>,
+    node_limit: usize,
+}
+
+impl IntegerProgram {
+    /// A program over one variable per objective coefficient.
+    pub fn new(objective: Vec<i64>) -> Self {
+        Self {
+            objective,
+            constraints: vec![],
+            node_limit: DEFAULT_NODE_LIMIT,
+        }
+    }
+
+    pub fn variables(&self) -> usize {
+        self.objective.len()
+    }
+
+    fn constrain(&mut self, coefficients: Vec<i64>, relation: Relation, rhs: i64) -> &mut Self {
+        assert_eq!(
+            coefficients.len(),
+            self.variables(),
+            "a coefficient for every variable is needed"
+        );
+        self.constraints.push(Constraint {
+            coefficients,
+            relation,
+            rhs,
+        });
+        self
+    }
+
+    /// Require `coefficients·x = rhs`.
+    pub fn equal(&mut self, coefficients: Vec<i64>, rhs: i64) -> &mut Self {
+        self.constrain(coefficients, Relation::Equal, rhs)
+    }
+
+    /// Require `coefficients·x ≤ rhs`.
+    pub fn at_most(&mut self, coefficients: Vec<i64>, rhs: i64) -> &mut Self {
+        self.constrain(coefficients, Relation::AtMost, rhs)
+    }
+
+    /// Require `coefficients·x ≥ rhs`.
+    pub fn at_least(&mut self, coefficients: Vec<i64>, rhs: i64) -> &mut Self {
+        self.constrain(coefficients, Relation::AtLeast, rhs)
+    }
+
+    /// Give up [`minimize`](Self::minimize) after solving `limit` subproblems.
+    pub fn node_limit(&mut self, limit: usize) -> &mut Self {
+        self.node_limit = limit;
+        self
+    }
+
+    /// Solve the problem without the integer requirement.
+    pub fn relax(&self) -> Relaxation {
+        let n = self.variables();
+        let slacks = self
+            .constraints
+            .iter()
+            .filter(|c| c.relation != Relation::Equal)
+            .count();
+
+        // bring every constraint into the form `a·x (+ or -) slack = b`.
+        let mut slack = n;
+        let rows: Vec<Vec<Rational>> = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                let mut row: Vec<Rational> = constraint
+                    .coefficients
+                    .iter()
+                    .map(|&c| Rational::from(c))
+                    .collect();
+                row.resize(n + slacks, Rational::ZERO);
+                match constraint.relation {
+                    Relation::Equal => {}
+                    Relation::AtMost => row[slack] = Rational::ONE,
+                    Relation::AtLeast => row[slack] = -Rational::ONE,
+                }
+                if constraint.relation != Relation::Equal {
+                    slack += 1;
+                }
+                row
+            })
+            .collect();
+        let rhs: Vec<Rational> = self
+            .constraints
+            .iter()
+            .map(|c| Rational::from(c.rhs))
+            .collect();
+        let mut cost: Vec<Rational> = self.objective.iter().map(|&c| Rational::from(c)).collect();
+        cost.resize(n + slacks, Rational::ZERO);
+
+        match simplex(rows, rhs, &cost) {
+            Relaxation::Optimal {
+                value,
+                mut variables,
+            } => {
+                variables.truncate(n);
+                Relaxation::Optimal { value, variables }
+            }
+            other => other,
+        }
+    }
+
+    /// The integer solution with the smallest objective value.
+    ///
+    /// Branch and bound does not terminate on its own for every program, e.g. `2x - 2y = 1`
+    /// keeps branching towards ever larger `x` and `y`, so the search stops with
+    /// [`Error::NodeLimit`] after the configured number of subproblems.
+    pub fn minimize(&self) -> Result<Solution, Error> {
+        let n = self.variables();
+        let mut best: Option<Solution> = None;
+        // every subproblem is the original one with tighter bounds `lower ≤ x ≤ upper`.
+        let mut stack: Vec<(Vec<i64>, Vec<Option<i64>>)> = vec![(vec![0; n], vec![None; n])];
+        let mut nodes = 0;
+
+        while let Some((lower, upper)) = stack.pop() {
+            if nodes == self.node_limit {
+                return Err(Error::NodeLimit(nodes));
+            }
+            nodes += 1;
+
+            let mut program = self.clone();
+            for variable in 0..n {
+                let mut unit = vec![0; n];
+                unit[variable] = 1;
+                if lower[variable] > 0 {
+                    program.at_least(unit.clone(), lower[variable]);
+                }
+                if let Some(upper) = upper[variable] {
+                    program.at_most(unit, upper);
+                }
+            }
+            let (value, variables) = match program.relax() {
+                Relaxation::Optimal { value, variables } => (value, variables),
+                Relaxation::Infeasible => continue,
+                // branching only tightens bounds, so this can only happen for the original problem.
+                Relaxation::Unbounded => return Err(Error::Unbounded),
+            };
+            // the objective of an integer solution is an integer, so it can't be below the ceiling.
+            if best
+                .as_ref()
+                .is_some_and(|best| value.ceil() >= i128::from(best.value))
+            {
+                continue;
+            }
+
+            let to_i64 = |value: i128| i64::try_from(value).map_err(|_| Error::Overflow);
+            match variables.iter().position(|v| !v.is_integer()) {
+                None => {
+                    best = Some(Solution {
+                        value: to_i64(value.floor())?,
+                        variables: variables
+                            .iter()
+                            .map(|v| to_i64(v.floor()))
+                            .collect::<Result<_, _>>()?,
+                    });
+                }
+                Some(fractional) => {
+                    let value = variables[fractional];
+                    let mut below = upper.clone();
+                    below[fractional] = Some(to_i64(value.floor())?);
+                    let mut above = lower.clone();
+                    above[fractional] = to_i64(value.ceil())?;
+                    stack.push((lower, below));
+                    stack.push((above, upper));
+                }
+            }
+        }
+
+        best.ok_or(Error::Infeasible)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A simplex tableau `[A | b]` with one basic variable per row.
+struct Tableau {
+    rows: Vec<Vec<Rational>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn rhs(&self, row: usize) -> Rational {
+        *self.rows[row].last().unwrap()
+    }
+
+    fn pivot(&mut self, row: usize, column: usize) {
+        let pivot = self.rows[row][column];
+        for value in &mut self.rows[row] {
+            *value = *value / pivot;
+        }
+        let pivot_row = self.rows[row].clone();
+        for (other, values) in self.rows.iter_mut().enumerate() {
+            let factor = values[column];
+            if other == row || factor.is_zero() {
+                continue;
+            }
+            for (value, &p) in values.iter_mut().zip(&pivot_row) {
+                *value = *value - factor * p;
+            }
+        }
+        self.basis[row] = column;
+    }
+
+    /// Minimise `cost` using the first `columns` columns, with Bland's rule to avoid cycling.
+    /// Returns `false` if the objective is unbounded.
+    fn optimize(&mut self, cost: &[Rational], columns: usize) -> bool {
+        loop {
+            let reduced_cost = |column: usize| {
+                self.rows
+                    .iter()
+                    .zip(&self.basis)
+                    .fold(cost[column], |reduced, (row, &basic)| {
+                        reduced - cost[basic] * row[column]
+                    })
+            };
+            let Some(entering) = (0..columns).find(|&c| reduced_cost(c) < Rational::ZERO) else {
+                return true;
+            };
+
+            let leaving = (0..self.rows.len())
+                .filter(|&r| self.rows[r][entering] > Rational::ZERO)
+                .min_by(|&a, &b| {
+                    let ratio = |r: usize| self.rhs(r) / self.rows[r][entering];
+                    ratio(a)
+                        .cmp(&ratio(b))
+                        .then(self.basis[a].cmp(&self.basis[b]))
+                });
+            match leaving {
+                Some(row) => self.pivot(row, entering),
+                None => return false,
+            }
+        }
+    }
+
+    fn value(&self, cost: &[Rational]) -> Rational {
+        (0..self.rows.len()).fold(Rational::ZERO, |value, r| {
+            value + cost[self.basis[r]] * self.rhs(r)
+        })
+    }
+}
+
+/// `minimize cost·x` subject to `A·x = b` and `x ≥ 0`, with the two-phase simplex method.
+fn simplex(a: Vec<Vec<Rational>>, b: Vec<Rational>, cost: &[Rational]) -> Relaxation {
+    let (m, n) = (a.len(), cost.len());
+
+    // phase one: find a feasible basis by minimising the sum of one artificial variable per row.
+    let rows = a
+        .into_iter()
+        .zip(b)
+        .enumerate()
+        .map(|(i, (mut row, rhs))| {
+            let sign = if rhs < Rational::ZERO {
+                -Rational::ONE
+            } else {
+                Rational::ONE
+            };
+            row.iter_mut().for_each(|v| *v = *v * sign);
+            row.extend((0..m).map(|j| {
+                if i == j {
+                    Rational::ONE
+                } else {
+                    Rational::ZERO
+                }
+            }));
+            row.push(rhs * sign);
+            row
+        })
+        .collect();
+    let mut tableau = Tableau {
+        rows,
+        basis: (n..n + m).collect(),
+    };
+
+    let mut artificial_cost = vec![Rational::ZERO; n];
+    artificial_cost.resize(n + m, Rational::ONE);
+    tableau.optimize(&artificial_cost, n + m);
+    if tableau.value(&artificial_cost) > Rational::ZERO {
+        return Relaxation::Infeasible;
+    }
+
+    // move artificial variables out of the basis, rows where that is impossible are redundant.
+    let mut r = 0;
+    while r < tableau.rows.len() {
+        if tableau.basis[r] < n {
+            r += 1;
+            continue;
+        }
+        match (0..n).find(|&c| !tableau.rows[r][c].is_zero()) {
+            Some(column) => {
+                tableau.pivot(r, column);
+                r += 1;
+            }
+            None => {
+                tableau.rows.remove(r);
+                tableau.basis.remove(r);
+            }
+        }
+    }
+    for row in &mut tableau.rows {
+        row.drain(n..n + m);
+    }
+
+    // phase two: optimise the actual objective from the feasible basis.
+    if !tableau.optimize(cost, n) {
+        return Relaxation::Unbounded;
+    }
+
+    let mut variables = vec![Rational::ZERO; n];
+    for (r, &basic) in tableau.basis.iter().enumerate() {
+        variables[basic] = tableau.rhs(r);
+    }
+    Relaxation::Optimal {
+        value: tableau.value(cost),
+        variables,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Error, IntegerProgram, Relaxation};
+    use crate::linalg::{LinearSystem, Rational};
+    use crate::template::property::Rng;
+
+    #[test]
+    fn solves_relaxation() {
+        // maximise x + y (minimise -x - y) with x + 2y ≤ 4, 3x + y ≤ 6
+        let mut program = IntegerProgram::new(vec![-1, -1]);
+        program.at_most(vec![1, 2], 4).at_most(vec![3, 1], 6);
+        assert_eq!(
+            program.relax(),
+            Relaxation::Optimal {
+                value: Rational::new(-14, 5),
+                variables: vec![Rational::new(8, 5), Rational::new(6, 5)]
+            }
+        );
+        assert_eq!(program.minimize().unwrap().value, -2);
+    }
+
+    #[test]
+    fn branches_to_integer_solutions() {
+        // buttons (3) (1,3) (2) (2,3) (0,2) (0,1) with joltages {3,5,4,7}
+        let mut program = IntegerProgram::new(vec![1; 6]);
+        program
+            .equal(vec![0, 0, 0, 0, 1, 1], 3)
+            .equal(vec![0, 1, 0, 0, 0, 1], 5)
+            .equal(vec![0, 0, 1, 1, 1, 0], 4)
+            .equal(vec![1, 1, 0, 1, 0, 0], 7);
+        let solution = program.minimize().unwrap();
+        assert_eq!(solution.value, 10);
+        assert_eq!(solution.variables.iter().sum::<i64>(), 10);
+    }
+
+    #[test]
+    fn detects_infeasible_and_unbounded_programs() {
+        let mut infeasible = IntegerProgram::new(vec![1, 1]);
+        infeasible.equal(vec![2, 2], 3);
+        assert_eq!(infeasible.minimize(), Err(Error::Infeasible));
+
+        let mut unbounded = IntegerProgram::new(vec![-1, 0]);
+        unbounded.at_least(vec![1, 1], 1);
+        assert_eq!(unbounded.relax(), Relaxation::Unbounded);
+        assert_eq!(unbounded.minimize(), Err(Error::Unbounded));
+
+        // the relaxation always has a fractional optimum further out
+        let mut diverging = IntegerProgram::new(vec![1, 1]);
+        diverging.equal(vec![2, -2], 1);
+        assert!(matches!(diverging.minimize(), Err(Error::NodeLimit(_))));
+        diverging.node_limit(50);
+        assert_eq!(diverging.minimize(), Err(Error::NodeLimit(50)));
+    }
+
+    #[test]
+    fn matches_enumeration() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let (rows, columns) = (rng.range(1..=3) as usize, rng.range(2..=5) as usize);
+            let a: Vec<Vec<i64>> = (0..rows)
+                .map(|_| (0..columns).map(|_| rng.range(0..=1) as i64).collect())
+                .collect();
+            let b: Vec<i64> = (0..rows).map(|_| rng.range(0..=6) as i64).collect();
+            let cost: Vec<i64> = (0..columns).map(|_| rng.range(1..=3) as i64).collect();
+
+            let mut program = IntegerProgram::new(cost.clone());
+            for (row, &rhs) in a.iter().zip(&b) {
+                program.equal(row.clone(), rhs);
+            }
+            let expected = LinearSystem::new(&a, &b)
+                .nonnegative_integer_solutions(&vec![6; columns])
+                .map(|x| x.iter().zip(&cost).map(|(x, c)| x * c).sum::<i64>())
+                .min();
+            assert_eq!(
+                program.minimize().ok().map(|s| s.value),
+                expected,
+                "seed {seed}"
+            );
+        }
+    }
+}
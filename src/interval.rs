@@ -0,0 +1,357 @@
+//! Sets of integers stored as sorted, non-overlapping inclusive ranges.
+//!
+//! ```
+//! # use advent_of_code::interval::IntervalSet;
+//! let fresh: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+//! assert_eq!(fresh.iter().collect::<Vec<_>>(), [3..=5, 10..=20]);
+//! assert!(fresh.contains(17));
+//! assert_eq!(fresh.len(), 14);
+//! ```
+
+use std::{fmt::Debug, ops::RangeInclusive};
+
+/// Integer types that can be stored in an [`IntervalSet`].
+pub trait Endpoint: Copy + Ord + Debug {
+    /// The next larger value, or `None` at the maximum.
+    fn succ(self) -> Option<Self>;
+
+    /// The next smaller value, or `None` at the minimum.
+    fn pred(self) -> Option<Self>;
+
+    /// The number of values in `start..=end`, widened so that full-width ranges fit.
+    /// Only the complete `u128` or `i128` range saturates at `u128::MAX`.
+    fn count(start: Self, end: Self) -> u128;
+
+    fn zero() -> Self;
+}
+
+macro_rules! impl_endpoint {
+    ($($t:ty),*) => {
+        $(
+            impl Endpoint for $t {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end.abs_diff(start) as u128).saturating_add(1)
+                }
+
+                fn zero() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_endpoint!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Whether a range ending at `end` lies before `start` with at least one value in between.
+fn ends_before<T: Endpoint>(end: T, start: T) -> bool {
+    end.succ().is_some_and(|next| next < start)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, disjoint and non-adjacent `(start, end)` pairs, both inclusive.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Add all values of `range`, merging it with overlapping and adjacent ranges.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| ends_before(e, start));
+        let last = self.ranges.partition_point(|&(s, _)| !ends_before(end, s));
+
+        let merged = if first < last {
+            (
+                start.min(self.ranges[first].0),
+                end.max(self.ranges[last - 1].1),
+            )
+        } else {
+            (start, end)
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove all values of `range`, splitting ranges that contain it.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first == last {
+            return;
+        }
+
+        let mut remaining = vec![];
+        let (s, _) = self.ranges[first];
+        if s < start {
+            remaining.push((s, start.pred().unwrap()));
+        }
+        let (_, e) = self.ranges[last - 1];
+        if e > end {
+            remaining.push((end.succ().unwrap(), e));
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    /// Whether `value` is in the set, in O(log n).
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// The range containing `value`, if any.
+    pub fn range_of(&self, value: T) -> Option<RangeInclusive<T>> {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges
+            .get(i)
+            .filter(|&&(s, _)| s <= value)
+            .map(|&(s, e)| s..=e)
+    }
+
+    /// The total number of values in the set, saturating like [`Endpoint::count`].
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().fold(0, |total: u128, &(s, e)| {
+            total.saturating_add(T::count(s, e))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of disjoint ranges.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The disjoint ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// All values in the set in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(|&(s, e)| {
+            std::iter::successors(Some(s), move |&v| v.succ().filter(|&next| next <= e))
+        })
+    }
+
+    /// Values in `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges: Vec<(T, T)> = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if x.0 <= y.0 => a.next(),
+                (Some(_), Some(_)) => b.next(),
+                (Some(_), None) => a.next(),
+                (None, _) => b.next(),
+            };
+            let Some(&(s, e)) = next else { break };
+            match ranges.last_mut() {
+                Some(last) if !ends_before(last.1, s) => last.1 = last.1.max(e),
+                _ => ranges.push((s, e)),
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Values in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (s, e) = (s1.max(s2), e1.min(e2));
+            if s <= e {
+                ranges.push((s, e));
+            }
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Values in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for &(s, e) in &self.ranges {
+            let mut start = Some(s);
+            while let Some(&(s2, e2)) = other.ranges.get(j) {
+                let Some(current) = start.filter(|&current| current <= e) else {
+                    break;
+                };
+                if e2 < current {
+                    j += 1;
+                    continue;
+                }
+                if s2 > e {
+                    break;
+                }
+                if s2 > current {
+                    ranges.push((current, s2.pred().unwrap()));
+                }
+                if e2 >= e {
+                    start = None;
+                    break;
+                }
+                start = e2.succ();
+                j += 1;
+            }
+            if let Some(current) = start.filter(|&current| current <= e) {
+                ranges.push((current, e));
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|r| (*r.start(), *r.end())).collect()
+    }
+
+    #[test]
+    fn coalesces_overlapping_and_adjacent_ranges() {
+        let s = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (6, 6), (30, 29)]);
+        assert_eq!(ranges(&s), [(3, 6), (10, 20)]);
+        assert_eq!(s.len(), 15);
+        assert_eq!(s.range_count(), 2);
+    }
+
+    #[test]
+    fn counts_full_width_ranges() {
+        let wide: IntervalSet<i8> = [-100..=100].into_iter().collect();
+        assert_eq!(wide.len(), 201);
+        let full: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(full.len(), 1 << 64);
+        let full: IntervalSet<i128> = [i128::MIN..=i128::MAX].into_iter().collect();
+        assert_eq!(full.len(), u128::MAX);
+    }
+
+    #[test]
+    fn checks_membership() {
+        let s = set(&[(-5, -1), (3, 5)]);
+        assert!(s.contains(-5));
+        assert!(!s.contains(0));
+        assert!(s.contains(5));
+        assert!(!s.contains(6));
+        assert_eq!(s.range_of(4), Some(3..=5));
+        assert_eq!(
+            s.values().collect::<Vec<_>>(),
+            [-5, -4, -3, -2, -1, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn removes_ranges() {
+        let mut s = set(&[(0, 10), (20, 30)]);
+        s.remove(5..=22);
+        assert_eq!(ranges(&s), [(0, 4), (23, 30)]);
+        s.remove(0..=0);
+        s.remove(30..=40);
+        assert_eq!(ranges(&s), [(1, 4), (23, 29)]);
+    }
+
+    #[test]
+    fn handles_extreme_values() {
+        let mut s: IntervalSet<u8> = [0..=10, 250..=255].into_iter().collect();
+        s.insert(11..=249);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [0..=255]);
+        s.remove(0..=0);
+        s.remove(255..=255);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [1..=254]);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30), (40, 50)]);
+        let b = set(&[(5, 24), (31, 35), (50, 60)]);
+
+        assert_eq!(ranges(&a.union(&b)), [(0, 35), (40, 60)]);
+        assert_eq!(ranges(&a.intersection(&b)), [(5, 10), (20, 24), (50, 50)]);
+        assert_eq!(ranges(&a.difference(&b)), [(0, 4), (25, 30), (40, 49)]);
+        assert_eq!(ranges(&b.difference(&a)), [(11, 19), (31, 35), (51, 60)]);
+    }
+
+    #[test]
+    fn matches_naive_set_operations() {
+        use std::collections::BTreeSet;
+
+        let inputs = [
+            set(&[(0, 3), (6, 9), (12, 12)]),
+            set(&[(2, 7), (9, 14)]),
+            set(&[(1, 1), (3, 3), (5, 5), (7, 7), (9, 9), (11, 11)]),
+            set(&[]),
+        ];
+        let values = |s: &IntervalSet<i32>| s.values().collect::<BTreeSet<_>>();
+
+        for a in &inputs {
+            for b in &inputs {
+                let (va, vb) = (values(a), values(b));
+                assert_eq!(values(&a.union(b)), &va | &vb);
+                assert_eq!(values(&a.intersection(b)), &va & &vb);
+                assert_eq!(values(&a.difference(b)), &va - &vb);
+            }
+        }
+    }
+}
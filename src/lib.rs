@@ -1,3 +1,14 @@
+pub mod compress;
+pub mod geometry;
+pub mod gf2;
+pub mod graph;
+pub mod grid;
+pub mod ilp;
+pub mod interval;
+pub mod linalg;
+pub mod parse;
+pub mod summed_area;
 pub mod template;
+pub mod union_find;
 
 // Use this file to add helper functions and additional modules.
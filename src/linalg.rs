@@ -0,0 +1,652 @@
+//! Exact linear algebra over the rationals and the integers.
+//!
+//! ```
+//! # use advent_of_code::linalg::LinearSystem;
+//! // x + y = 3, y + z = 5
+//! let system = LinearSystem::new(&[vec![1, 1, 0], vec![0, 1, 1]], &[3, 5]);
+//! assert_eq!(system.free_variables(), [2]);
+//! let solutions: Vec<Vec<i64>> = system.nonnegative_integer_solutions(&[3, 3, 5]).collect();
+//! assert_eq!(solutions, [vec![0, 3, 2], vec![1, 2, 3], vec![2, 1, 4], vec![3, 0, 5]]);
+//! ```
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A matrix of integers, as a list of rows.
+pub type Matrix = Vec<Vec<i128>>;
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A fraction in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+
+    /// Panics if `denominator` is zero.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "denominator must not be zero");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub const fn integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The value as an integer, or `None` if it is a proper fraction.
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// The largest integer not greater than the value.
+    pub fn floor(self) -> i128 {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    /// The smallest integer not less than the value.
+    pub fn ceil(self) -> i128 {
+        -(-self.numerator).div_euclid(self.denominator)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::integer(i128::from(value))
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Bring `matrix` into reduced row echelon form.
+/// Returns the pivot column of every non-zero row, the remaining rows are zero.
+pub fn row_reduce(matrix: &mut [Vec<Rational>]) -> Vec<usize> {
+    let columns = matrix.first().map_or(0, Vec::len);
+    let mut pivots = vec![];
+
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(pivot_row) = (row..matrix.len()).find(|&r| !matrix[r][column].is_zero()) else {
+            continue;
+        };
+        matrix.swap(row, pivot_row);
+
+        let pivot = matrix[row][column];
+        for value in &mut matrix[row] {
+            *value = *value / pivot;
+        }
+        let pivot_row = matrix[row].clone();
+        for (other, values) in matrix.iter_mut().enumerate() {
+            let factor = values[column];
+            if other == row || factor.is_zero() {
+                continue;
+            }
+            for (value, &pivot_value) in values.iter_mut().zip(&pivot_row).skip(column) {
+                *value = *value - factor * pivot_value;
+            }
+        }
+
+        pivots.push(column);
+    }
+
+    pivots
+}
+
+/// A system of linear equations `A·x = b`, solved exactly.
+///
+/// Every solution is a particular solution plus a combination of the null space,
+/// parametrised by the values of the free variables.
+#[derive(Clone, Debug)]
+pub struct LinearSystem {
+    /// The reduced augmented matrix `[A | b]`.
+    rows: Vec<Vec<Rational>>,
+    pivots: Vec<usize>,
+    variables: usize,
+}
+
+impl LinearSystem {
+    /// One row of `a` and one entry of `b` per equation, one column of `a` per variable.
+    pub fn new(a: &[Vec<i64>], b: &[i64]) -> Self {
+        assert_eq!(a.len(), b.len(), "every equation needs a right-hand side");
+        let variables = a.first().map_or(0, Vec::len);
+        let mut rows: Vec<Vec<Rational>> = a
+            .iter()
+            .zip(b)
+            .map(|(row, &rhs)| {
+                assert_eq!(row.len(), variables, "all equations need the same length");
+                row.iter()
+                    .chain([&rhs])
+                    .map(|&v| Rational::from(v))
+                    .collect()
+            })
+            .collect();
+        let pivots = row_reduce(&mut rows);
+
+        Self {
+            rows,
+            pivots,
+            variables,
+        }
+    }
+
+    /// Whether the system has any solution.
+    pub fn is_consistent(&self) -> bool {
+        self.pivots.last() != Some(&self.variables)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.pivots.iter().filter(|&&p| p < self.variables).count()
+    }
+
+    /// The variables that can be chosen freely, ascending.
+    pub fn free_variables(&self) -> Vec<usize> {
+        (0..self.variables)
+            .filter(|v| !self.pivots.contains(v))
+            .collect()
+    }
+
+    /// The solution where the free variables take the given values, in the order of [`Self::free_variables`].
+    /// Returns `None` if the system is inconsistent.
+    pub fn solve_with(&self, free_values: &[Rational]) -> Option<Vec<Rational>> {
+        if !self.is_consistent() {
+            return None;
+        }
+        let free = self.free_variables();
+        assert_eq!(
+            free.len(),
+            free_values.len(),
+            "a value for every free variable is needed"
+        );
+
+        let mut solution = vec![Rational::ZERO; self.variables];
+        for (&variable, &value) in free.iter().zip(free_values) {
+            solution[variable] = value;
+        }
+        for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+            solution[pivot] = free.iter().fold(row[self.variables], |value, &f| {
+                value - row[f] * solution[f]
+            });
+        }
+        Some(solution)
+    }
+
+    /// The solution with all free variables set to zero.
+    pub fn particular(&self) -> Option<Vec<Rational>> {
+        self.solve_with(&vec![Rational::ZERO; self.variables - self.rank()])
+    }
+
+    /// A basis of the solutions of `A·x = 0`, one vector per free variable.
+    pub fn null_space(&self) -> Vec<Vec<Rational>> {
+        let free = self.free_variables();
+        free.iter()
+            .map(|&variable| {
+                let mut vector = vec![Rational::ZERO; self.variables];
+                vector[variable] = Rational::ONE;
+                for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+                    vector[pivot] = -row[variable];
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// All solutions with integer values in `0..=upper_bounds[variable]`.
+    ///
+    /// Enumerates every combination of free variable values within their bounds,
+    /// so this is only feasible for few free variables with small bounds.
+    pub fn nonnegative_integer_solutions<'a>(
+        &'a self,
+        upper_bounds: &'a [i64],
+    ) -> impl Iterator<Item = Vec<i64>> + 'a {
+        assert_eq!(upper_bounds.len(), self.variables);
+        let free = self.free_variables();
+        let mut values: Option<Vec<i64>> = self.is_consistent().then(|| vec![0; free.len()]);
+
+        std::iter::from_fn(move || {
+            loop {
+                let current = values.as_mut()?;
+                let candidate: Vec<Rational> = current.iter().map(|&v| Rational::from(v)).collect();
+
+                // advance the free variables like an odometer.
+                let mut i = 0;
+                loop {
+                    if i == current.len() {
+                        values = None;
+                        break;
+                    }
+                    if current[i] < upper_bounds[free[i]] {
+                        current[i] += 1;
+                        break;
+                    }
+                    current[i] = 0;
+                    i += 1;
+                }
+
+                let solution: Option<Vec<i64>> = self
+                    .solve_with(&candidate)?
+                    .into_iter()
+                    .zip(upper_bounds)
+                    .map(|(value, &bound)| {
+                        let value = i64::try_from(value.to_integer()?).ok()?;
+                        (0..=bound).contains(&value).then_some(value)
+                    })
+                    .collect();
+                if solution.is_some() {
+                    return solution;
+                }
+            }
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn to_i128(a: &[Vec<i64>]) -> Matrix {
+    a.iter()
+        .map(|row| row.iter().map(|&v| i128::from(v)).collect())
+        .collect()
+}
+
+fn identity(n: usize) -> Matrix {
+    (0..n)
+        .map(|i| (0..n).map(|j| i128::from(i == j)).collect())
+        .collect()
+}
+
+/// `rows[target] -= factor * rows[source]`
+fn subtract_row(rows: &mut [Vec<i128>], target: usize, source: usize, factor: i128) {
+    for c in 0..rows[target].len() {
+        rows[target][c] -= factor * rows[source][c];
+    }
+}
+
+/// `columns[target] -= factor * columns[source]`
+fn subtract_column(matrix: &mut [Vec<i128>], target: usize, source: usize, factor: i128) {
+    for row in matrix {
+        row[target] -= factor * row[source];
+    }
+}
+
+/// The row-style Hermite normal form `H = U·A` with a unimodular `U`, returned as `(H, U)`.
+///
+/// `H` is in row echelon form with positive pivots, and the entries above each pivot
+/// are non-negative and smaller than the pivot.
+pub fn hermite_normal_form(a: &[Vec<i64>]) -> (Matrix, Matrix) {
+    let mut h = to_i128(a);
+    let mut u = identity(h.len());
+    let columns = h.first().map_or(0, Vec::len);
+    let mut row = 0;
+
+    for column in 0..columns {
+        if row == h.len() {
+            break;
+        }
+
+        // reduce the column below `row` with the euclidean algorithm until a single entry is left.
+        while let Some(pivot) = (row..h.len())
+            .filter(|&r| h[r][column] != 0)
+            .min_by_key(|&r| h[r][column].abs())
+        {
+            h.swap(row, pivot);
+            u.swap(row, pivot);
+
+            let mut reduced = true;
+            for r in row + 1..h.len() {
+                let factor = h[r][column] / h[row][column];
+                subtract_row(&mut h, r, row, factor);
+                subtract_row(&mut u, r, row, factor);
+                reduced &= h[r][column] == 0;
+            }
+            if reduced {
+                break;
+            }
+        }
+
+        if h[row][column] == 0 {
+            continue;
+        }
+        if h[row][column] < 0 {
+            h[row].iter_mut().for_each(|v| *v = -*v);
+            u[row].iter_mut().for_each(|v| *v = -*v);
+        }
+        for r in 0..row {
+            let factor = h[r][column].div_euclid(h[row][column]);
+            subtract_row(&mut h, r, row, factor);
+            subtract_row(&mut u, r, row, factor);
+        }
+        row += 1;
+    }
+
+    (h, u)
+}
+
+/// The Smith normal form `D = U·A·V` with unimodular `U` and `V`, returned as `(D, U, V)`.
+///
+/// `D` is diagonal with non-negative entries, and every diagonal entry divides the next.
+pub fn smith_normal_form(a: &[Vec<i64>]) -> (Matrix, Matrix, Matrix) {
+    let mut d = to_i128(a);
+    let (rows, columns) = (d.len(), d.first().map_or(0, Vec::len));
+    let mut u = identity(rows);
+    let mut v = identity(columns);
+
+    for t in 0..rows.min(columns) {
+        loop {
+            // move the smallest non-zero entry of the remaining submatrix to the diagonal.
+            let Some((r, c)) = (t..rows)
+                .flat_map(|r| (t..columns).map(move |c| (r, c)))
+                .filter(|&(r, c)| d[r][c] != 0)
+                .min_by_key(|&(r, c)| d[r][c].abs())
+            else {
+                return (d, u, v);
+            };
+            d.swap(t, r);
+            u.swap(t, r);
+            for row in d.iter_mut().chain(v.iter_mut()) {
+                row.swap(t, c);
+            }
+
+            for r in t + 1..rows {
+                let factor = d[r][t] / d[t][t];
+                subtract_row(&mut d, r, t, factor);
+                subtract_row(&mut u, r, t, factor);
+            }
+            for c in t + 1..columns {
+                let factor = d[t][c] / d[t][t];
+                subtract_column(&mut d, c, t, factor);
+                subtract_column(&mut v, c, t, factor);
+            }
+
+            let remainders =
+                (t + 1..rows).any(|r| d[r][t] != 0) || (t + 1..columns).any(|c| d[t][c] != 0);
+            if remainders {
+                continue;
+            }
+
+            // the pivot must divide the rest, otherwise pull a row with a remainder into the pivot row.
+            let indivisible =
+                (t + 1..rows).find(|&r| (t + 1..columns).any(|c| d[r][c] % d[t][t] != 0));
+            match indivisible {
+                Some(r) => {
+                    subtract_row(&mut d, t, r, -1);
+                    subtract_row(&mut u, t, r, -1);
+                }
+                None => break,
+            }
+        }
+
+        if d[t][t] < 0 {
+            d[t].iter_mut().for_each(|x| *x = -*x);
+            u[t].iter_mut().for_each(|x| *x = -*x);
+        }
+    }
+
+    (d, u, v)
+}
+
+/// A basis of all integer vectors `x` with `A·x = 0`: every integer solution is an integer combination of it.
+pub fn integer_null_space(a: &[Vec<i64>]) -> Matrix {
+    let columns = a.first().map_or(0, Vec::len);
+    let transposed: Vec<Vec<i64>> = (0..columns)
+        .map(|c| a.iter().map(|row| row[c]).collect())
+        .collect();
+
+    // `H = U·Aᵀ`, so the rows of `U` belonging to the zero rows of `H` are mapped to zero by `A`.
+    let (h, u) = hermite_normal_form(&transposed);
+    h.iter()
+        .zip(u)
+        .filter(|(row, _)| row.iter().all(|&v| v == 0))
+        .map(|(_, basis)| basis)
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        LinearSystem, Rational, hermite_normal_form, integer_null_space, smith_normal_form,
+    };
+
+    fn multiply(a: &[Vec<i128>], b: &[Vec<i128>]) -> Vec<Vec<i128>> {
+        a.iter()
+            .map(|row| {
+                (0..b[0].len())
+                    .map(|c| row.iter().zip(b).map(|(x, b_row)| x * b_row[c]).sum())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn wide(a: &[Vec<i64>]) -> Vec<Vec<i128>> {
+        a.iter()
+            .map(|row| row.iter().map(|&v| i128::from(v)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn computes_with_rationals() {
+        let half = Rational::new(2, 4);
+        assert_eq!(half, Rational::new(-1, -2));
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half * Rational::from(4), Rational::from(2));
+        assert_eq!((half / Rational::from(-3)).to_string(), "-1/6");
+        assert!(Rational::new(1, 3) < half);
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert_eq!(
+            (Rational::new(-7, 2).floor(), Rational::new(-7, 2).ceil()),
+            (-4, -3)
+        );
+        assert_eq!(
+            (Rational::from(3).floor(), Rational::from(3).ceil()),
+            (3, 3)
+        );
+    }
+
+    #[test]
+    fn solves_systems_exactly() {
+        // buttons (3) (1,3) (2) (2,3) (0,2) (0,1) with joltages {3,5,4,7}
+        let a = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        let b = [3, 5, 4, 7];
+        let system = LinearSystem::new(&a, &b);
+        assert!(system.is_consistent());
+        assert_eq!(system.rank(), 4);
+        assert_eq!(system.free_variables().len(), 2);
+
+        let check = |x: &[Rational]| {
+            for (row, &rhs) in a.iter().zip(&b) {
+                let sum = row
+                    .iter()
+                    .zip(x)
+                    .fold(Rational::ZERO, |acc, (&c, &x)| acc + Rational::from(c) * x);
+                assert_eq!(sum, Rational::from(rhs));
+            }
+        };
+        check(&system.particular().unwrap());
+        for vector in system.null_space() {
+            for row in &a {
+                let sum = row
+                    .iter()
+                    .zip(&vector)
+                    .fold(Rational::ZERO, |acc, (&c, &x)| acc + Rational::from(c) * x);
+                assert!(sum.is_zero());
+            }
+        }
+
+        let minimum = system
+            .nonnegative_integer_solutions(&[7; 6])
+            .map(|x| x.iter().sum::<i64>())
+            .min();
+        assert_eq!(minimum, Some(10));
+    }
+
+    #[test]
+    fn detects_inconsistent_systems() {
+        let system = LinearSystem::new(&[vec![1, 1], vec![2, 2]], &[1, 3]);
+        assert!(!system.is_consistent());
+        assert_eq!(system.particular(), None);
+        assert_eq!(system.nonnegative_integer_solutions(&[5, 5]).count(), 0);
+    }
+
+    #[test]
+    fn finds_only_integer_solutions() {
+        // 2x = y, x + y = 3 + z
+        let system = LinearSystem::new(&[vec![2, -1, 0], vec![1, 1, -1]], &[0, 3]);
+        let solutions: Vec<Vec<i64>> = system.nonnegative_integer_solutions(&[10; 3]).collect();
+        assert_eq!(
+            solutions,
+            [vec![1, 2, 0], vec![2, 4, 3], vec![3, 6, 6], vec![4, 8, 9]]
+        );
+    }
+
+    #[test]
+    fn computes_hermite_normal_form() {
+        let a = vec![vec![2, 3, 6, 2], vec![5, 6, 1, 6], vec![8, 3, 1, 1]];
+        let (h, u) = hermite_normal_form(&a);
+        assert_eq!(multiply(&u, &wide(&a)), h);
+        assert_eq!(
+            h,
+            [vec![1, 0, 50, -11], vec![0, 3, 28, -2], vec![0, 0, 61, -13]]
+        );
+    }
+
+    #[test]
+    fn computes_smith_normal_form() {
+        let a = vec![vec![2, 4, 4], vec![-6, 6, 12], vec![10, -4, -16]];
+        let (d, u, v) = smith_normal_form(&a);
+        assert_eq!(multiply(&multiply(&u, &wide(&a)), &v), d);
+        assert_eq!(d, [vec![2, 0, 0], vec![0, 6, 0], vec![0, 0, 12]]);
+    }
+
+    #[test]
+    fn finds_integer_null_space() {
+        let a = vec![vec![1, 2, 3], vec![2, 4, 6]];
+        let basis = integer_null_space(&a);
+        assert_eq!(basis.len(), 2);
+        for vector in &basis {
+            for row in &a {
+                let sum: i128 = row
+                    .iter()
+                    .zip(vector)
+                    .map(|(&c, x)| i128::from(c) * x)
+                    .sum();
+                assert_eq!(sum, 0);
+            }
+        }
+    }
+}
@@ -1,13 +1,17 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::Error;
+use advent_of_code::template::commands::{
+    all, download, read, scaffold, solve, stars, time, watch,
+};
 use args::{AppArguments, parse};
+use std::process;
 
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+#[cfg(feature = "dashboard")]
+use advent_of_code::template::commands::dashboard;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, commands::scaffold};
     use std::process;
 
     pub enum AppArguments {
@@ -16,17 +20,19 @@ mod args {
         },
         Read {
             day: Day,
+            refresh: bool,
         },
         Scaffold {
             day: Day,
             download: bool,
-            overwrite: bool,
+            options: scaffold::Options,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            watch: bool,
         },
         All {
             release: bool,
@@ -36,6 +42,9 @@ mod args {
             day: Option<Day>,
             store: bool,
         },
+        Stars,
+        #[cfg(feature = "dashboard")]
+        Dashboard,
         #[cfg(feature = "today")]
         Today,
     }
@@ -57,22 +66,41 @@ mod args {
                     store,
                 }
             }
+            Some("stars") => AppArguments::Stars,
+            #[cfg(feature = "dashboard")]
+            Some("dashboard") => AppArguments::Dashboard,
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
+                refresh: args.contains("--refresh"),
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
+            Some("scaffold") => {
+                let day = args.free_from_str()?;
+                let download = args.contains("--download");
+                let template = args.opt_value_from_str("--template")?;
+                let example_answers = [
+                    args.opt_value_from_str("--example-one")?,
+                    args.opt_value_from_str("--example-two")?,
+                ];
+                AppArguments::Scaffold {
+                    day,
+                    download,
+                    options: scaffold::Options {
+                        // last, so that a following option is not taken for its value.
+                        overwrite: overwrite_option(&mut args)?,
+                        template,
+                        example_answers,
+                    },
+                }
+            }
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                watch: args.contains("--watch"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,52 +121,103 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// `--overwrite=<files>` or `--overwrite <files>`, a bare `--overwrite` only replaces the module file.
+    fn overwrite_option(args: &mut pico_args::Arguments) -> Result<scaffold::Overwrite, pico_args::Error> {
+        match args.opt_value_from_str("--overwrite") {
+            Ok(overwrite) => return Ok(overwrite.unwrap_or_default()),
+            // nothing or another flag follows, e.g. `--overwrite --download`.
+            Err(pico_args::Error::OptionWithoutAValue(_)) => {}
+            Err(pico_args::Error::Utf8ArgumentParsingFailed { value, .. }) if value.starts_with('-') => {}
+            Err(e) => return Err(e),
+        }
+        args.contains("--overwrite");
+        Ok(scaffold::Overwrite::MODULE)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::overwrite_option;
+        use advent_of_code::template::commands::scaffold::Overwrite;
+
+        fn parse(args: &[&str]) -> (Result<Overwrite, pico_args::Error>, usize) {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            let overwrite = overwrite_option(&mut args);
+            (overwrite, args.finish().len())
+        }
+
+        #[test]
+        fn parses_overwrite_values() {
+            let inputs = Overwrite { inputs: true, ..Overwrite::default() };
+            assert_eq!(parse(&["--overwrite=inputs"]).0.unwrap(), inputs);
+            assert_eq!(parse(&["--overwrite", "inputs"]).0.unwrap(), inputs);
+            assert_eq!(parse(&["--overwrite", "inputs"]).1, 0);
+            assert_eq!(parse(&[]).0.unwrap(), Overwrite::default());
+        }
+
+        #[test]
+        fn parses_bare_overwrite() {
+            assert_eq!(parse(&["--overwrite"]).0.unwrap(), Overwrite::MODULE);
+            let (overwrite, remaining) = parse(&["--overwrite", "--unknown"]);
+            assert_eq!((overwrite.unwrap(), remaining), (Overwrite::MODULE, 1));
+            assert!(parse(&["--overwrite", "nope"]).0.is_err());
+        }
+    }
 }
 
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
+    let args = parse().unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        process::exit(1);
+    });
+
+    if let Err(err) = run(args) {
+        eprintln!("Error: {err}");
+        process::exit(err.exit_code());
+    }
+}
+
+fn run(args: AppArguments) -> Result<(), Error> {
+    match args {
+        AppArguments::All { release } => all::handle(release),
+        AppArguments::Time { day, all, store } => time::handle(day, all, store),
+        AppArguments::Stars => stars::handle(),
+        #[cfg(feature = "dashboard")]
+        AppArguments::Dashboard => dashboard::handle(),
+        AppArguments::Download { day } => download::handle(day),
+        AppArguments::Read { day, refresh } => read::handle(day, refresh),
+        AppArguments::Scaffold {
+            day,
+            download,
+            options,
+        } => {
+            scaffold::handle(day, &options)?;
+            if download {
+                download::handle(day)?;
+            }
+            Ok(())
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
+        AppArguments::Solve {
+            day,
+            release,
+            dhat,
+            submit,
+            watch,
+        } => {
+            if watch {
+                watch::handle(day, release)
+            } else {
+                solve::handle(day, release, dhat, submit)
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+        }
+        #[cfg(feature = "today")]
+        AppArguments::Today => match Day::today() {
+            Some(day) => {
+                scaffold::handle(day, &scaffold::Options::default())?;
+                download::handle(day)?;
+                read::handle(day, false)
             }
+            None => Err(Error::NotAdventDay),
         },
-    };
+    }
 }
@@ -0,0 +1,461 @@
+//! Helpers for parsing puzzle inputs without allocating.
+//!
+//! A [`Span`] is a piece of the input that remembers where it came from,
+//! so errors point at the line and column of the malformed input.
+//!
+//! ```
+//! # use advent_of_code::parse::Span;
+//! let input = Span::new("[.##.] (3) (1,3) {3,5,4,7}");
+//! let mut parts = input.split(' ');
+//! let lights = parts.next().unwrap().strip_brackets('[', ']').unwrap();
+//! let buttons: Vec<Vec<usize>> = input
+//!     .groups('(', ')')
+//!     .map(|group| group?.ints().collect())
+//!     .collect::<Result<_, _>>()
+//!     .unwrap();
+//! assert_eq!(lights.as_str(), ".##.");
+//! assert_eq!(buttons, [vec![3], vec![1, 3]]);
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The text is not a number.
+    NotANumber,
+    /// The number does not fit into the named integer type.
+    Overflow(&'static str),
+    /// Something was expected but not found, e.g. "`,`".
+    Expected(String),
+    /// A bracket was opened but never closed.
+    Unclosed(char),
+    /// A line contained more or fewer numbers than required.
+    Count { expected: usize, found: usize },
+    /// The error of a [`FromStr`] implementation.
+    Invalid(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::NotANumber => write!(f, "expected a number"),
+            ErrorKind::Overflow(ty) => write!(f, "number does not fit into {ty}"),
+            ErrorKind::Expected(what) => write!(f, "expected {what}"),
+            ErrorKind::Unclosed(bracket) => write!(f, "unclosed `{bracket}`"),
+            ErrorKind::Count { expected, found } => {
+                write!(f, "expected {expected} numbers, found {found}")
+            }
+            ErrorKind::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A parse error with the 1-based position of the offending text.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+/* -------------------------------------------------------------------------- */
+
+/// Integer types that can be extracted from the input.
+pub trait Int: Copy {
+    const NAME: &'static str;
+    const SIGNED: bool;
+
+    /// `self * 10 + digit`, or `self * 10 - digit` for negative numbers.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+
+    fn zero() -> Self;
+}
+
+macro_rules! impl_int {
+    ($signed:literal: $($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const NAME: &'static str = stringify!($t);
+                const SIGNED: bool = $signed;
+
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(10)?;
+                    let digit = <$t>::try_from(digit).ok()?;
+                    if negative {
+                        shifted.checked_sub(digit)
+                    } else {
+                        shifted.checked_add(digit)
+                    }
+                }
+
+                fn zero() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(false: u8, u16, u32, u64, u128, usize);
+impl_int!(true: i8, i16, i32, i64, i128, isize);
+
+/* -------------------------------------------------------------------------- */
+
+/// A part of the input, see the [module documentation](self).
+#[derive(Clone, Copy, Debug)]
+pub struct Span<'a> {
+    source: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Span<'a> {
+    /// A span covering the whole input.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            start: 0,
+            end: source.len(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        &self.source[self.start..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The span of `text`, which must be a slice of this span.
+    fn sub(&self, text: &'a str) -> Self {
+        let start = text.as_ptr() as usize - self.source.as_ptr() as usize;
+        Self {
+            source: self.source,
+            start,
+            end: start + text.len(),
+        }
+    }
+
+    /// The 1-based line and column of a byte offset into the source.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let before = &self.source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// An error pointing at the start of this span.
+    pub fn error(&self, kind: ErrorKind) -> Error {
+        self.error_at(self.start, kind)
+    }
+
+    fn error_at(&self, offset: usize, kind: ErrorKind) -> Error {
+        let (line, column) = self.position(offset);
+        Error { line, column, kind }
+    }
+
+    /// The 1-based line this span starts on.
+    pub fn line(&self) -> usize {
+        self.position(self.start).0
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.as_str().trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.as_str().lines().map(move |line| span.sub(line))
+    }
+
+    /// Blocks of lines separated by blank lines.
+    pub fn blocks(&self) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.as_str()
+            .split("\n\n")
+            .map(move |block| span.sub(block.trim_matches('\n')))
+            .filter(|block| !block.is_empty())
+    }
+
+    /// Parts separated by `separator`.
+    pub fn split(&self, separator: char) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.as_str()
+            .split(separator)
+            .map(move |part| span.sub(part))
+    }
+
+    /// Parts separated by whitespace.
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.as_str()
+            .split_whitespace()
+            .map(move |word| span.sub(word))
+    }
+
+    /// The parts before and after the first `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), Error> {
+        let text = self.as_str();
+        text.split_once(separator)
+            .map(|(before, after)| (self.sub(before), self.sub(after)))
+            .ok_or_else(|| self.error_at(self.end, ErrorKind::Expected(format!("`{separator}`"))))
+    }
+
+    /// The contents of a span enclosed in `open` and `close`, e.g. `(1,3)`.
+    pub fn strip_brackets(&self, open: char, close: char) -> Result<Span<'a>, Error> {
+        let text = self.as_str();
+        let inner = text
+            .strip_prefix(open)
+            .ok_or_else(|| self.error(ErrorKind::Expected(format!("`{open}`"))))?;
+        let inner = inner
+            .strip_suffix(close)
+            .ok_or_else(|| self.error(ErrorKind::Unclosed(open)))?;
+        Ok(self.sub(inner))
+    }
+
+    /// The contents of every top-level group enclosed in `open` and `close`.
+    /// Nested groups are part of the contents of their enclosing group.
+    pub fn groups(
+        &self,
+        open: char,
+        close: char,
+    ) -> impl Iterator<Item = Result<Span<'a>, Error>> + use<'a> {
+        let span = *self;
+        let mut rest = self.as_str();
+
+        std::iter::from_fn(move || {
+            let start = rest.find(open)?;
+            let mut depth = 0;
+            for (i, c) in rest[start..].char_indices() {
+                if c == open {
+                    depth += 1;
+                } else if c == close {
+                    depth -= 1;
+                    if depth == 0 {
+                        let inner = &rest[start + open.len_utf8()..start + i];
+                        rest = &rest[start + i + close.len_utf8()..];
+                        return Some(Ok(span.sub(inner)));
+                    }
+                }
+            }
+            let unclosed = span.sub(&rest[start..]);
+            rest = "";
+            Some(Err(unclosed.error(ErrorKind::Unclosed(open))))
+        })
+    }
+
+    /// Parse the whole span as an integer, e.g. `-12`.
+    pub fn int<T: Int>(&self) -> Result<T, Error> {
+        let text = self.as_str();
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) if T::SIGNED => (true, digits),
+            _ => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(self.error(ErrorKind::NotANumber));
+        }
+        digits.bytes().try_fold(T::zero(), |value, digit| {
+            value
+                .push_digit(digit - b'0', negative)
+                .ok_or_else(|| self.error(ErrorKind::Overflow(T::NAME)))
+        })
+    }
+
+    /// All integers in the span, skipping any other text.
+    /// A `-` directly before a number makes it negative if `T` is signed.
+    ///
+    /// ```
+    /// # use advent_of_code::parse::Span;
+    /// let numbers: Vec<i32> = Span::new("p=0,4 v=3,-3").ints().collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(numbers, [0, 4, 3, -3]);
+    /// ```
+    pub fn ints<T: Int>(&self) -> impl Iterator<Item = Result<T, Error>> + use<'a, T> {
+        let span = *self;
+        let text = self.as_str();
+        let bytes = text.as_bytes();
+        let mut i = 0;
+
+        std::iter::from_fn(move || {
+            while i < bytes.len() && !bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i == bytes.len() {
+                return None;
+            }
+            let negative = T::SIGNED && i > 0 && bytes[i - 1] == b'-';
+            let start = if negative { i - 1 } else { i };
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            Some(span.sub(&text[start..i]).int())
+        })
+    }
+
+    /// Exactly `N` integers, e.g. the coordinates of a point.
+    pub fn ints_array<const N: usize, T: Int>(&self) -> Result<[T; N], Error> {
+        let mut result = [T::zero(); N];
+        let mut found = 0;
+        for value in self.ints() {
+            if found < N {
+                result[found] = value?;
+            }
+            found += 1;
+        }
+        if found == N {
+            Ok(result)
+        } else {
+            Err(self.error(ErrorKind::Count { expected: N, found }))
+        }
+    }
+
+    /// Parse the span with [`FromStr`].
+    pub fn parse<T: FromStr>(&self) -> Result<T, Error>
+    where
+        T::Err: Display,
+    {
+        self.as_str()
+            .parse()
+            .map_err(|e: T::Err| self.error(ErrorKind::Invalid(e.to_string())))
+    }
+
+    /// Parse every line with `parse_line`.
+    pub fn parse_lines<T>(
+        &self,
+        parse_line: impl FnMut(Span<'a>) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.lines().map(parse_line).collect()
+    }
+
+    /// Parse every block of lines with `parse_block`.
+    pub fn parse_blocks<T>(
+        &self,
+        parse_block: impl FnMut(Span<'a>) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.blocks().map(parse_block).collect()
+    }
+}
+
+impl<'a> From<&'a str> for Span<'a> {
+    fn from(source: &'a str) -> Self {
+        Self::new(source)
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// All integers in `text`, see [`Span::ints`]. Panics on numbers that do not fit into `T`.
+pub fn ints<T: Int>(text: &str) -> impl Iterator<Item = T> + use<'_, T> {
+    Span::new(text)
+        .ints()
+        .map(|value| value.unwrap_or_else(|e| panic!("{e}")))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Error, ErrorKind, Span, ints};
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(ints::<u64>("12-34, x=7").collect::<Vec<_>>(), [12, 34, 7]);
+        assert_eq!(
+            ints::<i64>("12-34, x=-7").collect::<Vec<_>>(),
+            [12, -34, -7]
+        );
+        assert_eq!(ints::<i8>("-128 127").collect::<Vec<_>>(), [-128, 127]);
+    }
+
+    #[test]
+    fn reports_overflow_with_position() {
+        let input = Span::new("1\n2 300\n");
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            line.ints::<u8>().nth(1),
+            Some(Err(Error {
+                line: 2,
+                column: 3,
+                kind: ErrorKind::Overflow("u8")
+            }))
+        );
+    }
+
+    #[test]
+    fn splits_lines_and_blocks() {
+        let input = Span::new("3-5\n10-14\n\n1\n5\n");
+        let blocks: Vec<_> = input.blocks().collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].line(), 4);
+
+        let ranges = blocks[0]
+            .parse_lines(|line| {
+                let (start, end) = line.split_once("-")?;
+                Ok((start.int::<u64>()?, end.int::<u64>()?))
+            })
+            .unwrap();
+        assert_eq!(ranges, [(3, 5), (10, 14)]);
+
+        let error = blocks[1].split_once("-").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 2));
+        assert_eq!(error.to_string(), "line 5, column 2: expected `-`");
+    }
+
+    #[test]
+    fn parses_bracket_groups() {
+        let line = Span::new("[.#] (1,(2)) {3}");
+        let groups: Vec<_> = line.groups('(', ')').map(|g| g.unwrap().as_str()).collect();
+        assert_eq!(groups, ["1,(2)"]);
+        assert_eq!(
+            line.words()
+                .next()
+                .unwrap()
+                .strip_brackets('[', ']')
+                .unwrap()
+                .as_str(),
+            ".#"
+        );
+
+        let error = Span::new("a\n(1, (2)")
+            .groups('(', ')')
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Unclosed('('));
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn counts_required_integers() {
+        assert_eq!(Span::new("1,2,3").ints_array::<3, u32>(), Ok([1, 2, 3]));
+        assert_eq!(
+            Span::new("1,2").ints_array::<3, u32>().unwrap_err().kind,
+            ErrorKind::Count {
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            Span::new("x").int::<u32>().unwrap_err().kind,
+            ErrorKind::NotANumber
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Summed-area tables: 2D prefix sums that give the sum of any rectangle of cells in constant time.
+//!
+//! ```
+//! # use advent_of_code::grid::Grid;
+//! # use advent_of_code::summed_area::SummedAreaTable;
+//! let grid = Grid::parse("12\n34\n56", |c| c.to_digit(10).unwrap()).unwrap();
+//! let sums = SummedAreaTable::from(&grid);
+//! assert_eq!(sums.sum((0, 1), (1, 2)), 3 + 4 + 5 + 6);
+//! assert_eq!(sums.total(), 21);
+//! ```
+
+use std::ops::{Add, Sub};
+
+use crate::grid::Grid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SummedAreaTable<T> {
+    width: usize,
+    height: usize,
+    /// `(width + 1) × (height + 1)` sums, entry `(x, y)` holds the sum of all cells left of `x` and above `y`.
+    sums: Vec<T>,
+}
+
+impl<T> SummedAreaTable<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Build the table for a `width × height` area whose cell `(x, y)` holds `cell(x, y)`.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let stride = width + 1;
+        let mut sums = vec![T::default(); stride * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                // inclusion-exclusion: the rectangle above plus the one to the left counts their overlap twice.
+                sums[(y + 1) * stride + x + 1] =
+                    cell(x, y) + sums[y * stride + x + 1] + sums[(y + 1) * stride + x]
+                        - sums[y * stride + x];
+            }
+        }
+        Self {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The sum of the cells from `min` to `max`, both included.
+    pub fn sum(&self, min: (usize, usize), max: (usize, usize)) -> T {
+        assert!(
+            min.0 <= max.0 && min.1 <= max.1,
+            "{min:?} is not the top left corner of {max:?}"
+        );
+        assert!(
+            max.0 < self.width && max.1 < self.height,
+            "{max:?} is outside of the {}x{} table",
+            self.width,
+            self.height
+        );
+        let at = |x: usize, y: usize| self.sums[y * (self.width + 1) + x];
+        // add before subtracting, so unsigned sums never go below zero on the way.
+        (at(max.0 + 1, max.1 + 1) + at(min.0, min.1))
+            - (at(min.0, max.1 + 1) + at(max.0 + 1, min.1))
+    }
+
+    /// The sum of all cells.
+    pub fn total(&self) -> T {
+        *self.sums.last().unwrap()
+    }
+}
+
+impl<T> From<&Grid<T>> for SummedAreaTable<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(grid: &Grid<T>) -> Self {
+        Self::from_fn(grid.width(), grid.height(), |x, y| grid[(x, y)])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::SummedAreaTable;
+    use crate::grid::Grid;
+    use crate::template::property::Rng;
+
+    #[test]
+    fn sums_rectangles() {
+        let mut rng = Rng::new(7);
+        let (width, height) = (9, 6);
+        let cells: Vec<i64> = (0..width * height)
+            .map(|_| rng.range(0..=20) as i64 - 10)
+            .collect();
+        let sums = SummedAreaTable::from_fn(width, height, |x, y| cells[y * width + x]);
+
+        for _ in 0..200 {
+            let (x1, x2) = (rng.range(0..=8) as usize, rng.range(0..=8) as usize);
+            let (y1, y2) = (rng.range(0..=5) as usize, rng.range(0..=5) as usize);
+            let (min, max) = ((x1.min(x2), y1.min(y2)), (x1.max(x2), y1.max(y2)));
+            let expected: i64 = (min.1..=max.1)
+                .flat_map(|y| (min.0..=max.0).map(move |x| (x, y)))
+                .map(|(x, y)| cells[y * width + x])
+                .sum();
+            assert_eq!(sums.sum(min, max), expected);
+        }
+        assert_eq!(sums.total(), cells.iter().sum::<i64>());
+    }
+
+    #[test]
+    fn sums_unsigned_cells() {
+        let grid = Grid::parse("90\n01", |c| c.to_digit(10).unwrap()).unwrap();
+        let sums = SummedAreaTable::from(&grid);
+        assert_eq!(sums.sum((1, 1), (1, 1)), 1);
+        assert_eq!(sums.sum((1, 0), (1, 1)), 1);
+        assert_eq!(sums.sum((0, 1), (1, 1)), 1);
+        assert_eq!(sums.sum((0, 0), (0, 0)), 9);
+        assert_eq!(sums.total(), 10);
+    }
+
+    #[test]
+    fn handles_empty_tables() {
+        let sums = SummedAreaTable::<u64>::from_fn(0, 3, |_, _| 1);
+        assert_eq!(sums.total(), 0);
+    }
+}
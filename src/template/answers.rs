@@ -0,0 +1,347 @@
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, config};
+
+/// The verdict advent of code returned for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after a previous wrong answer.
+    RateLimited,
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Derive the verdict from the response text printed by aoc-cli.
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Self::Incorrect)
+        } else if response.contains("You gave an answer too recently") {
+            Some(Self::RateLimited)
+        } else if response.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the answer was judged wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::Incorrect | Self::TooHigh | Self::TooLow)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Incorrect => "incorrect",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::RateLimited => "rate_limited",
+            Self::WrongLevel => "wrong_level",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "incorrect" => Ok(Self::Incorrect),
+            "too_high" => Ok(Self::TooHigh),
+            "too_low" => Ok(Self::TooLow),
+            "rate_limited" => Ok(Self::RateLimited),
+            "wrong_level" => Ok(Self::WrongLevel),
+            x => Err(format!("Unknown verdict `{x}`.")),
+        }
+    }
+}
+
+/// Represents a single submitted answer.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Represents all answers submitted so far.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Submission>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(&config().paths.answers)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(&config().paths.answers)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Add a submission, replacing an earlier submission of the same answer.
+    pub fn record(&mut self, submission: Submission) {
+        self.data.retain(|s| {
+            s.day != submission.day || s.part != submission.part || s.answer != submission.answer
+        });
+        self.data.push(submission);
+        self.data.sort_by_key(|s| (s.day, s.part));
+    }
+
+    /// Returns the accepted answer for a part, if there is one.
+    pub fn correct_answer(&self, day: Day, part: u8) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|s| s.day == day && s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    pub fn is_part_solved(&self, day: Day, part: u8) -> bool {
+        self.correct_answer(day, part).is_some()
+    }
+
+    /// Check an answer against earlier verdicts before submitting it.
+    /// Returns the reason if the answer is known to be wrong or the part is already solved.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Option<String> {
+        if let Some(correct) = self.correct_answer(day, part) {
+            return Some(format!("part {part} was already solved with {correct}."));
+        }
+
+        let value = answer.parse::<i128>().ok();
+
+        self.data
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .find_map(|s| {
+                let bound = s.answer.parse::<i128>().ok();
+                match s.verdict {
+                    Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+                        if s.answer == answer =>
+                    {
+                        Some(format!("this answer was already judged {}.", s.verdict))
+                    }
+                    Verdict::TooHigh if matches!((value, bound), (Some(v), Some(b)) if v > b) => {
+                        Some(format!("{} was already judged too high.", s.answer))
+                    }
+                    Verdict::TooLow if matches!((value, bound), (Some(v), Some(b)) if v < b) => {
+                        Some(format!("{} was already judged too low.", s.answer))
+                    }
+                    _ => None,
+                }
+            })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .filter(|part| *part == 1.0 || *part == 2.0)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .and_then(|verdict| Verdict::from_str(verdict).ok())
+            .ok_or("Expected submission.verdict to be a verdict.")?;
+
+        Ok(Submission {
+            day,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            part: part as u8,
+            answer: answer.clone(),
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Answers, Submission, Verdict};
+
+    fn submission(day: u8, part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            day: crate::template::Day::new(day).unwrap(),
+            part,
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Incorrect)
+        );
+        assert_eq!(Verdict::from_response("something else"), None);
+    }
+
+    #[test]
+    fn finds_correct_answers() {
+        let mut answers = Answers::default();
+        answers.record(submission(1, 1, "10", Verdict::TooLow));
+        answers.record(submission(1, 1, "42", Verdict::Correct));
+        answers.record(submission(1, 2, "7", Verdict::Incorrect));
+
+        assert_eq!(answers.correct_answer(day!(1), 1), Some("42"));
+        assert_eq!(answers.is_part_solved(day!(1), 2), false);
+    }
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let mut answers = Answers::default();
+        answers.record(submission(1, 1, "100", Verdict::TooHigh));
+        answers.record(submission(1, 1, "10", Verdict::TooLow));
+        answers.record(submission(1, 1, "50", Verdict::Incorrect));
+
+        assert!(answers.check(day!(1), 1, "100").is_some());
+        assert!(answers.check(day!(1), 1, "101").is_some());
+        assert!(answers.check(day!(1), 1, "9").is_some());
+        assert!(answers.check(day!(1), 1, "50").is_some());
+        assert!(answers.check(day!(1), 1, "51").is_none());
+        assert!(answers.check(day!(1), 2, "101").is_none());
+    }
+
+    #[test]
+    fn rejects_solved_parts() {
+        let mut answers = Answers::default();
+        answers.record(submission(1, 1, "42", Verdict::Correct));
+
+        assert!(answers.check(day!(1), 1, "43").is_some());
+    }
+
+    #[test]
+    fn replaces_resubmitted_answers() {
+        let mut answers = Answers::default();
+        answers.record(submission(1, 1, "42", Verdict::RateLimited));
+        answers.record(submission(1, 1, "42", Verdict::Correct));
+
+        assert_eq!(answers.data.len(), 1);
+        assert_eq!(answers.is_part_solved(day!(1), 1), true);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut answers = Answers::default();
+        answers.record(submission(3, 2, "abc", Verdict::Correct));
+
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.correct_answer(day!(3), 2), Some("abc"));
+    }
+}
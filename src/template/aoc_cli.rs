@@ -4,7 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{Day, config};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -33,13 +33,15 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
+/// Download the puzzle description only, e.g. to include part two once it is unlocked.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
 
     let args = build_args(
-        "read",
+        "download",
         &[
-            "--description-only".into(),
+            "--overwrite".into(),
+            "--puzzle-only".into(),
             "--puzzle-file".into(),
             puzzle_path,
         ],
@@ -72,37 +74,50 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
+/// Submit an answer. The response is echoed to stdout and returned in [`Output::stdout`].
 pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
 fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    config().input_path(day).display().to_string()
 }
 
 fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+    config().puzzle_path(day).display().to_string()
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    if let Some(year) = config().year {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
 
+    if let Some(session_file) = &config().session_file {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file.display().to_string());
+    }
+
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
     cmd_args
@@ -1,5 +1,5 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{Error, all_days, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(is_release: bool) -> Result<(), Error> {
+    run_multi(&all_days().collect(), is_release, false)?.result()
 }
@@ -0,0 +1,254 @@
+/// Interactive overview of the whole calendar.
+use std::{
+    fs,
+    io::{self, Write, stdout},
+    path::Path,
+    process::Command,
+};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::template::answers::Answers;
+use crate::template::commands::{download, read, solve, time};
+use crate::template::readme_stars::{self, Stars};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Error, all_days, config};
+
+const ANSI_REVERSE: &str = "\x1b[7m";
+
+/// Represents the state of a single day.
+struct DayStatus {
+    day: Day,
+    scaffolded: bool,
+    has_input: bool,
+    examples: usize,
+    stars: Stars,
+    timing: Option<Timing>,
+}
+
+impl DayStatus {
+    fn collect() -> Vec<Self> {
+        let answers = Answers::read_from_file();
+        let timings = Timings::read_from_file();
+
+        all_days()
+            .map(|day| DayStatus {
+                day,
+                scaffolded: Path::new(&get_path_for_bin(day)).exists(),
+                has_input: fs::metadata(config().input_path(day)).is_ok_and(|m| m.len() > 0),
+                examples: count_examples(day),
+                stars: readme_stars::for_day(day, &answers),
+                timing: timings.data.iter().find(|t| t.day == day).cloned(),
+            })
+            .collect()
+    }
+
+    fn format_row(&self) -> String {
+        let check = |value: bool| if value { "✔" } else { "·" };
+        let stars = format!("{:<2}", "*".repeat(self.stars.count().into()));
+        let part_1 = self.timing.as_ref().and_then(|t| t.part_1.as_deref());
+        let part_2 = self.timing.as_ref().and_then(|t| t.part_2.as_deref());
+
+        format!(
+            " {:<4} {:<7} {:<6} {:<9} {:<6} {:<12} {:<12}",
+            self.day.to_string(),
+            check(self.scaffolded),
+            check(self.has_input),
+            self.examples,
+            stars,
+            part_1.unwrap_or("-"),
+            part_2.unwrap_or("-"),
+        )
+    }
+}
+
+/// Count the non-empty example files of a day, e.g. `11-1.txt` and `11-2.txt`.
+fn count_examples(day: Day) -> usize {
+    let Ok(entries) = fs::read_dir(&config().paths.examples) else {
+        return 0;
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            let is_day = name == format!("{day}.txt") || name.starts_with(&format!("{day}-"));
+            is_day && entry.metadata().is_ok_and(|m| m.len() > 0)
+        })
+        .count()
+}
+
+#[derive(Clone, Copy)]
+enum Action {
+    Solve,
+    Test,
+    Time,
+    Download,
+    Read,
+}
+
+impl Action {
+    fn from_key(code: KeyCode) -> Option<Self> {
+        match code {
+            KeyCode::Enter | KeyCode::Char('s') => Some(Self::Solve),
+            KeyCode::Char('t') => Some(Self::Test),
+            KeyCode::Char('b') => Some(Self::Time),
+            KeyCode::Char('d') => Some(Self::Download),
+            KeyCode::Char('r') => Some(Self::Read),
+            _ => None,
+        }
+    }
+
+    fn run(self, day: Day) -> Result<(), Error> {
+        match self {
+            Action::Solve => solve::handle(day, false, false, None),
+            Action::Test => {
+                let day_padded = day.to_string();
+                let args = ["test", "--bin", &day_padded];
+                let status = Command::new("cargo")
+                    .args(args)
+                    .status()
+                    .map_err(|source| Error::Command {
+                        command: format!("cargo {}", args.join(" ")),
+                        source,
+                    })?;
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(Error::TestsFailed { day, status })
+                }
+            }
+            Action::Time => time::handle(Some(day), false, false),
+            Action::Download => download::handle(day),
+            Action::Read => read::handle(day, false),
+        }
+    }
+}
+
+/// Switches the terminal to the dashboard and restores it when dropped,
+/// also if a command fails or panics.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(days: &[DayStatus], selected: usize) -> io::Result<()> {
+    let mut out = stdout();
+    let title = match config().year {
+        Some(year) => format!("Advent of Code {year}"),
+        None => "Advent of Code".into(),
+    };
+    let total: u32 = days.iter().map(|d| u32::from(d.stars.count())).sum();
+
+    let mut lines = vec![
+        format!(" {ANSI_BOLD}{title}{ANSI_RESET} - {total} stars"),
+        String::new(),
+        format!(
+            "{ANSI_BOLD} {:<4} {:<7} {:<6} {:<9} {:<6} {:<12} {:<12}{ANSI_RESET}",
+            "Day", "Module", "Input", "Examples", "Stars", "Part 1", "Part 2"
+        ),
+    ];
+
+    for (i, day) in days.iter().enumerate() {
+        if i == selected {
+            lines.push(format!("{ANSI_REVERSE}{}{ANSI_RESET}", day.format_row()));
+        } else {
+            lines.push(day.format_row());
+        }
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        " {ANSI_ITALIC}↑/↓ select · enter/s solve · t test · b time · d download · r read · q quit{ANSI_RESET}"
+    ));
+
+    queue!(out, Clear(ClearType::All))?;
+    for (row, line) in lines.iter().enumerate() {
+        queue!(
+            out,
+            MoveTo(0, u16::try_from(row).unwrap_or(u16::MAX)),
+            Print(line)
+        )?;
+    }
+    out.flush()
+}
+
+/// Leave the dashboard, run an action and wait for a key press before returning.
+fn run_action(screen: Screen, action: Action, day: Day) -> io::Result<Screen> {
+    drop(screen);
+
+    if let Err(e) = action.run(day) {
+        eprintln!("Error: {e}");
+    }
+
+    println!();
+    println!("{ANSI_ITALIC}Press any key to return to the dashboard.{ANSI_RESET}");
+
+    // raw mode is needed to read a single key press, the output stays visible until then.
+    terminal::enable_raw_mode()?;
+    let key = wait_for_key();
+    terminal::disable_raw_mode()?;
+    key?;
+
+    Screen::enter()
+}
+
+fn wait_for_key() -> io::Result<KeyEvent> {
+    loop {
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            return Ok(key);
+        }
+    }
+}
+
+pub fn handle() -> Result<(), Error> {
+    dashboard().map_err(Error::Terminal)
+}
+
+fn dashboard() -> io::Result<()> {
+    let mut days = DayStatus::collect();
+    let mut selected = Day::today().map_or(0, |day| usize::from(day.into_inner()) - 1);
+    let mut screen = Screen::enter()?;
+
+    loop {
+        draw(&days, selected)?;
+
+        let key = wait_for_key()?;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Up | KeyCode::Char('k') => selected = selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => selected = (selected + 1).min(days.len() - 1),
+            code => {
+                if let Some(action) = Action::from_key(code) {
+                    screen = run_action(screen, action, days[selected].day)?;
+                    days = DayStatus::collect();
+                }
+            }
+        }
+    }
+
+    drop(screen);
+    Ok(())
+}
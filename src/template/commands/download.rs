@@ -1,16 +1,7 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::{Day, Error, aoc_cli};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+pub fn handle(day: Day) -> Result<(), Error> {
+    aoc_cli::check().map_err(|_| Error::AocCliMissing)?;
+    aoc_cli::download(day)?;
+    Ok(())
 }
@@ -1,6 +1,10 @@
 pub mod all;
+#[cfg(feature = "dashboard")]
+pub mod dashboard;
 pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
 pub mod time;
+pub mod watch;
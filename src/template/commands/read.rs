@@ -1,17 +1,39 @@
-use std::process;
+use std::fs;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::{Day, Error, aoc_cli, config, markdown};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+/// Whether part one was solved after the puzzle was saved, so part two is missing from it.
+fn is_missing_part_two(day: Day, puzzle: &str) -> bool {
+    !puzzle.contains("--- Part Two ---") && Answers::read_from_file().is_part_solved(day, 1)
+}
+
+fn fetch(day: Day) -> Result<(), Error> {
+    aoc_cli::check().map_err(|_| Error::AocCliMissing)?;
+    aoc_cli::download_puzzle(day)?;
+    Ok(())
+}
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+/// Render the saved puzzle description of a day.
+/// It is only fetched if it was not downloaded yet, part two was unlocked since or `refresh` is set.
+pub fn handle(day: Day, refresh: bool) -> Result<(), Error> {
+    let path = config().puzzle_path(day);
+
+    let puzzle = match fs::read_to_string(&path) {
+        Ok(puzzle) if !refresh && !is_missing_part_two(day, &puzzle) => puzzle,
+        cached => {
+            match (fetch(day), cached) {
+                (Ok(()), _) => {}
+                // the saved description is still useful when offline.
+                (Err(e), Ok(_)) => {
+                    eprintln!("Could not refresh the puzzle, showing saved description: {e}")
+                }
+                (Err(e), Err(_)) => return Err(e),
+            }
+            fs::read_to_string(&path).map_err(|source| Error::Puzzle { day, source })?
+        }
     };
+
+    println!("{}", markdown::render(&puzzle));
+    Ok(())
 }
@@ -1,79 +1,463 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+    fmt::Display,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 
-use crate::template::Day;
+use crate::template::{Day, Error, aoc_cli, config};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// The files that scaffolding may replace if they exist already.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Overwrite {
+    pub module: bool,
+    pub inputs: bool,
+    pub examples: bool,
+}
+
+impl Overwrite {
+    pub const MODULE: Self = Self {
+        module: true,
+        inputs: false,
+        examples: false,
+    };
+}
+
+impl FromStr for Overwrite {
+    type Err = OverwriteFromStrError;
+
+    /// Parses a comma-separated list of `module`, `inputs`, `examples` and `all`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut overwrite = Self::default();
+        for target in s.split(',') {
+            match target.trim() {
+                "module" => overwrite.module = true,
+                "inputs" => overwrite.inputs = true,
+                "examples" => overwrite.examples = true,
+                "all" => {
+                    overwrite = Self {
+                        module: true,
+                        inputs: true,
+                        examples: true,
+                    }
+                }
+                _ => return Err(OverwriteFromStrError),
+            }
+        }
+        Ok(overwrite)
+    }
+}
+
+/// An error which can be returned when parsing [`Overwrite`].
+#[derive(Debug)]
+pub struct OverwriteFromStrError;
+
+impl std::error::Error for OverwriteFromStrError {}
+
+impl Display for OverwriteFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `module`, `inputs`, `examples` or `all`")
+    }
+}
+
+/// Options for scaffolding a day.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub overwrite: Overwrite,
+    /// Name of a template in the templates directory. Uses the built-in template if unset.
+    pub template: Option<String>,
+    /// Expected answers for the example input, substituted into the generated tests.
+    pub example_answers: [Option<String>; 2],
+}
+
+/// Read a template from the templates directory, e.g. `templates/grid.txt`.
+fn read_template(name: &str) -> Result<String, String> {
+    let dir = &config().paths.templates;
+    let path = dir.join(format!("{name}.txt"));
+
+    fs::read_to_string(&path).map_err(|e| {
+        let available = fs::read_dir(dir)
+            .map(|entries| {
+                let mut names: Vec<String> = entries
+                    .filter_map(|entry| {
+                        let path = entry.ok()?.path();
+                        let is_template = path.extension().is_some_and(|ext| ext == "txt");
+                        is_template.then(|| path.file_stem()?.to_str().map(Into::into))?
+                    })
+                    .collect();
+                names.sort();
+                names.join(", ")
+            })
+            .unwrap_or_default();
+
+        format!(
+            "could not read template \"{}\": {e}. Available templates: {}",
+            path.display(),
+            if available.is_empty() {
+                "-"
+            } else {
+                &available
+            }
+        )
+    })
+}
+
+/// Read the puzzle title from a downloaded puzzle description, e.g. `Day 1: Secret Entrance`.
+fn read_title(day: Day) -> Option<String> {
+    let puzzle = fs::read_to_string(config().puzzle_path(day)).ok()?;
+    puzzle.lines().find_map(|line| {
+        // aoc-cli escapes the leading dashes, e.g. `\--- Day 1: Secret Entrance ---`.
+        let title = line.trim_start_matches('#').trim().trim_start_matches('\\');
+        let title = title.strip_prefix("---")?.strip_suffix("---")?.trim();
+        title.starts_with("Day").then(|| title.to_string())
+    })
+}
+
+fn format_answer(answer: Option<&String>, quoted: bool) -> String {
+    match answer {
+        Some(answer) if quoted => format!("Some({answer:?})"),
+        Some(answer) => format!("Some({answer})"),
+        None => "None".into(),
+    }
+}
+
+/// Substitute placeholders in a template:
+///  - `%DAY_NUMBER%`: the day without padding, e.g. `1`.
+///  - `%YEAR%`: the configured year. Lines using it are left out if no year is configured,
+///    so that they do not render broken links.
+///  - `%TITLE%`: the puzzle title if the puzzle was downloaded, e.g. `Day 1: Secret Entrance`.
+///  - `%EXAMPLE_PART_ONE%`, `%EXAMPLE_PART_TWO%`: the expected example answer, e.g. `Some(42)` or `None`.
+///  - `%EXAMPLE_PART_ONE_STR%`, `%EXAMPLE_PART_TWO_STR%`: the same as string literal, e.g. `Some("42")`.
+fn render(
+    template: &str,
+    day: Day,
+    year: Option<u16>,
+    title: Option<&str>,
+    options: &Options,
+) -> String {
+    let [part_one, part_two] = &options.example_answers;
+
+    let template: String = match year {
+        Some(year) => template.replace("%YEAR%", &year.to_string()),
+        None => template
+            .split_inclusive('\n')
+            .filter(|line| !line.contains("%YEAR%"))
+            .collect(),
+    };
+    template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace(
+            "%TITLE%",
+            &title.map_or_else(|| format!("Day {}", day.into_inner()), Into::into),
+        )
+        .replace(
+            "%EXAMPLE_PART_ONE_STR%",
+            &format_answer(part_one.as_ref(), true),
+        )
+        .replace(
+            "%EXAMPLE_PART_TWO_STR%",
+            &format_answer(part_two.as_ref(), true),
+        )
+        .replace(
+            "%EXAMPLE_PART_ONE%",
+            &format_answer(part_one.as_ref(), false),
+        )
+        .replace(
+            "%EXAMPLE_PART_TWO%",
+            &format_answer(part_two.as_ref(), false),
+        )
+}
+
+/// Creates a file if it is missing or `overwrite` is set.
+/// Returns `None` if the file exists already and was left untouched.
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<Option<File>, io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
     } else {
         file.create_new(true);
     }
-    file.truncate(true).write(true).open(path)
+
+    match file.truncate(true).write(true).open(path) {
+        Ok(file) => Ok(Some(file)),
+        Err(e) if !overwrite && e.kind() == io::ErrorKind::AlreadyExists => Ok(None),
+        Err(e) => Err(e),
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/* -------------------------------------------------------------------------- */
+
+/// The kinds of files created by scaffolding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileKind {
+    Module,
+    Input,
+    Example,
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+impl Display for FileKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FileKind::Module => "module file",
+            FileKind::Input => "input file",
+            FileKind::Example => "example file",
+        })
+    }
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileStatus {
+    Created,
+    Overwritten,
+    Skipped,
+}
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+/// A file touched by scaffolding.
+#[derive(Clone, Debug)]
+pub struct ScaffoldedFile {
+    pub kind: FileKind,
+    pub path: PathBuf,
+    pub status: FileStatus,
+}
+
+impl Display for ScaffoldedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let empty = if self.kind == FileKind::Module {
+            ""
+        } else {
+            "empty "
+        };
+        match self.status {
+            FileStatus::Created => write!(f, "Created {empty}{}", self.kind),
+            FileStatus::Overwritten => write!(f, "Overwrote {}", self.kind),
+            FileStatus::Skipped => write!(f, "Skipped existing {}", self.kind),
+        }?;
+        write!(f, " \"{}\"", self.path.display())
+    }
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Template(String),
+    IO {
+        kind: FileKind,
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Template(e) => write!(f, "Failed to read template: {e}"),
+            ScaffoldError::IO { kind, path, source } => {
+                write!(
+                    f,
+                    "Failed to create {kind} \"{}\": {source}",
+                    path.display()
+                )
+            }
         }
     }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Keeps track of the files written while scaffolding, so that they can be restored
+/// if a later step fails and no half-scaffolded day is left behind.
+#[derive(Default)]
+struct Transaction {
+    created: Vec<PathBuf>,
+    replaced: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl Transaction {
+    /// Write `contents` to `path` unless the file exists and `overwrite` is unset.
+    fn write(&mut self, path: &Path, contents: &[u8], overwrite: bool) -> io::Result<FileStatus> {
+        let previous = if overwrite {
+            match fs::read(path) {
+                Ok(previous) => Some(previous),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => return Err(e),
+            }
+        } else {
+            None
+        };
+
+        let Some(mut file) = safe_create_file(path, overwrite)? else {
+            return Ok(FileStatus::Skipped);
+        };
+
+        // the file is tracked before writing, so a failed write is rolled back as well.
+        let status = match previous {
+            Some(previous) => {
+                self.replaced.push((path.into(), previous));
+                FileStatus::Overwritten
+            }
+            None => {
+                self.created.push(path.into());
+                FileStatus::Created
+            }
+        };
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+        file.write_all(contents)?;
+        Ok(status)
+    }
+
+    /// Remove created files and restore the contents of overwritten ones.
+    fn rollback(self) {
+        for path in self.created.iter().rev() {
+            let _ = fs::remove_file(path);
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+        for (path, contents) in self.replaced {
+            let _ = fs::write(path, contents);
         }
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+/// Create the module, input and example files for a day.
+/// If any file cannot be written, the files written so far are rolled back.
+pub fn scaffold(day: Day, options: &Options) -> Result<Vec<ScaffoldedFile>, ScaffoldError> {
+    let template = match &options.template {
+        Some(name) => read_template(name).map_err(ScaffoldError::Template)?,
+        None => MODULE_TEMPLATE.to_string(),
+    };
+    let module = render(
+        &template,
+        day,
+        config().year,
+        read_title(day).as_deref(),
+        options,
+    );
+
+    let files = [
+        (
+            FileKind::Module,
+            PathBuf::from(format!("src/bin/{day}.rs")),
+            module.as_bytes(),
+            options.overwrite.module,
+        ),
+        (
+            FileKind::Input,
+            config().input_path(day),
+            &[][..],
+            options.overwrite.inputs,
+        ),
+        (
+            FileKind::Example,
+            config().example_path(day),
+            &[][..],
+            options.overwrite.examples,
+        ),
+    ];
+
+    let mut transaction = Transaction::default();
+    let mut scaffolded = Vec::with_capacity(files.len());
+
+    for (kind, path, contents, overwrite) in files {
+        match transaction.write(&path, contents, overwrite) {
+            Ok(status) => scaffolded.push(ScaffoldedFile { kind, path, status }),
+            Err(source) => {
+                transaction.rollback();
+                return Err(ScaffoldError::IO { kind, path, source });
+            }
         }
     }
 
+    Ok(scaffolded)
+}
+
+/// Fetch the puzzle description for `%TITLE%` unless it was downloaded already.
+/// Scaffolding still works offline or before the puzzle unlocks, the title then falls back to `Day N`.
+fn fetch_puzzle(day: Day) {
+    if !config().puzzle_path(day).exists() && aoc_cli::check().is_ok() {
+        let _ = aoc_cli::download_puzzle(day);
+    }
+}
+
+pub fn handle(day: Day, options: &Options) -> Result<(), Error> {
+    fetch_puzzle(day);
+    for file in scaffold(day, options)? {
+        println!("{file}");
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::{FileStatus, Options, Transaction, render};
+    use crate::day;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn leaves_out_year_lines_without_year() {
+        let template = "//! %TITLE%\n//! https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%\nsolution!(%DAY_NUMBER%);\n";
+        let options = Options::default();
+        assert_eq!(
+            render(template, day!(7), Some(2025), None, &options),
+            "//! Day 7\n//! https://adventofcode.com/2025/day/7\nsolution!(7);\n"
+        );
+        assert_eq!(
+            render(template, day!(7), None, None, &options),
+            "//! Day 7\nsolution!(7);\n"
+        );
+    }
+
+    #[test]
+    fn skips_existing_files() {
+        let dir = temp_dir("skip");
+        let path = dir.join("01.txt");
+        fs::write(&path, "existing").unwrap();
+
+        let mut transaction = Transaction::default();
+        let status = transaction.write(&path, b"new", false).unwrap();
+
+        assert_eq!(status, FileStatus::Skipped);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "existing");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rolls_back_written_files() {
+        let dir = temp_dir("rollback");
+        let created = dir.join("01.rs");
+        let replaced = dir.join("01.txt");
+        fs::write(&replaced, "existing").unwrap();
+
+        let mut transaction = Transaction::default();
+        assert_eq!(
+            transaction.write(&created, b"new", false).unwrap(),
+            FileStatus::Created
+        );
+        assert_eq!(
+            transaction.write(&replaced, b"new", true).unwrap(),
+            FileStatus::Overwritten
+        );
+        assert!(
+            transaction
+                .write(&dir.join("missing/01.txt"), b"", false)
+                .is_err()
+        );
+        transaction.rollback();
+
+        assert!(!created.exists());
+        assert_eq!(fs::read_to_string(&replaced).unwrap(), "existing");
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
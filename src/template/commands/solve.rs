@@ -1,21 +1,26 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{Day, Error, run_multi::child_commands};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) -> Result<(), Error> {
+    let mut profile_args = vec![];
 
     if dhat {
-        cmd_args.extend([
+        profile_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
     } else if release {
-        cmd_args.push("--release".to_string());
+        profile_args.push("--release".to_string());
     }
 
+    // build separately, so a compile error can be told apart from a failing solution.
+    child_commands::build_solution(day, &profile_args, false)?;
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+    cmd_args.extend(profile_args);
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,12 +28,18 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    let command_error = |source| Error::Command {
+        command: format!("cargo {}", cmd_args.join(" ")),
+        source,
+    };
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
-        .unwrap();
+        .map_err(command_error)?;
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().map_err(command_error)?;
+    Error::from_solution_status(day, status)
 }
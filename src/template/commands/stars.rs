@@ -0,0 +1,27 @@
+use crate::template::answers::Answers;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Error, config, readme_stars};
+
+pub fn handle() -> Result<(), Error> {
+    let stars = readme_stars::collect(&Answers::read_from_file());
+
+    for day_stars in &stars {
+        let count = day_stars.count();
+        println!(
+            "{ANSI_BOLD}Day {}{ANSI_RESET}: {}",
+            day_stars.day,
+            "⭐".repeat(count.into())
+        );
+    }
+
+    let total: u32 = stars.iter().map(|s| u32::from(s.count())).sum();
+    println!("{ANSI_BOLD}Total{ANSI_RESET}: {total} ⭐");
+
+    println!();
+    readme_stars::update(&stars, config().year).map_err(|source| Error::Readme {
+        table: "stars",
+        source,
+    })?;
+    println!("Stored updated stars.");
+
+    Ok(())
+}
@@ -2,9 +2,9 @@ use std::collections::HashSet;
 
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, Error, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(day: Option<Day>, run_all: bool, store: bool) -> Result<(), Error> {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
@@ -21,20 +21,23 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(&days_to_run, true, true)?;
+    let Some(timings) = &run.timings else {
+        return run.result();
+    };
 
+    // store the days that succeeded even if another one failed.
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        let merged_timings = stored_timings.merge(timings);
+        merged_timings.store_file().map_err(Error::Timings)?;
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
-        }
+        readme_benchmarks::update(merged_timings).map_err(|source| Error::Readme {
+            table: "benchmarks",
+            source,
+        })?;
+        println!("Stored updated benchmarks.");
     }
+
+    run.result()
 }
@@ -0,0 +1,121 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Error, config};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Modification times of all files that affect a day's solution.
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// Collect the files that affect a day's solution:
+///  1. the solution module `src/bin/<day>.rs`.
+///  2. the library, i.e. every file in `src/` outside of `src/bin/`.
+///  3. the day's input and all of its example files, e.g. `11-1.txt` and `11-2.txt`.
+fn watched_files(day: Day) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/bin/{day}.rs")),
+        config().input_path(day),
+    ];
+
+    collect_files(Path::new("src"), &mut files, &|path| !path.ends_with("bin"));
+    collect_files(&config().paths.examples, &mut files, &|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(&day.to_string()))
+    });
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Recursively collect the files in `dir` for which `include` holds.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>, include: &dyn Fn(&Path) -> bool) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+        if !include(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files, include);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+fn snapshot(day: Day) -> Snapshot {
+    watched_files(day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Run `cargo` with `args`, returning whether it succeeded.
+fn cargo(args: &[&str]) -> Result<bool, Error> {
+    let status = Command::new("cargo")
+        .args(args)
+        .status()
+        .map_err(|source| Error::Command {
+            command: format!("cargo {}", args.join(" ")),
+            source,
+        })?;
+    Ok(status.success())
+}
+
+/// Run the tests against the examples, then the solution against the real input.
+fn run(day: Day, release: bool) -> Result<(), Error> {
+    let day_padded = day.to_string();
+    let profile: &[&str] = if release { &["--release"] } else { &[] };
+
+    print!("{ANSI_CLEAR}");
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    let mut test_args = vec!["test", "--quiet", "--bin", &day_padded];
+    test_args.extend(profile);
+
+    if cargo(&test_args)? {
+        let mut run_args = vec!["run", "--quiet", "--bin", &day_padded];
+        run_args.extend(profile);
+        cargo(&run_args)?;
+    } else {
+        println!("Tests failed, skipped running the real input.");
+    }
+
+    println!();
+    println!("{ANSI_ITALIC}Watching for changes... (press Ctrl+C to stop){ANSI_RESET}");
+    Ok(())
+}
+
+/// Re-run a day whenever its module, the library or its data files change.
+/// Uses polling, so it works the same on every platform.
+pub fn handle(day: Day, release: bool) -> Result<(), Error> {
+    let mut last = snapshot(day);
+    run(day, release)?;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = snapshot(day);
+        if current != last {
+            // editors may write a file in several steps, wait for them to settle.
+            thread::sleep(POLL_INTERVAL);
+            last = snapshot(day);
+            run(day, release)?;
+        }
+    }
+}
@@ -0,0 +1,441 @@
+/// Project configuration, read from `aoc.toml` in the repository root.
+///
+/// Every setting can be overridden with an environment variable named after its key,
+/// e.g. `year` with `AOC_YEAR` and `bench.max_samples` with `AOC_BENCH_MAX_SAMPLES`.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use crate::template::Day;
+
+static CONFIG_FILE_PATH: &str = "aoc.toml";
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The year of advent of code that is being solved.
+    pub year: Option<u16>,
+    /// UTC offset of the advent of code server in hours, used to determine the current day.
+    pub server_utc_offset: i32,
+    /// Session cookie file passed to aoc-cli. aoc-cli falls back to its default location if unset.
+    pub session_file: Option<PathBuf>,
+    pub paths: Paths,
+    pub readme: Readme,
+    pub bench: Bench,
+    pub submit: Submit,
+    pub checks: Checks,
+}
+
+#[derive(Clone, Debug)]
+pub struct Paths {
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+    pub puzzles: PathBuf,
+    pub timings: PathBuf,
+    pub answers: PathBuf,
+    pub templates: PathBuf,
+}
+
+#[derive(Clone, Debug)]
+pub struct Readme {
+    pub path: PathBuf,
+    pub benchmarks_marker: String,
+    pub stars_marker: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Bench {
+    /// Approximate time budget for benching a single part.
+    pub duration: Duration,
+    pub min_samples: u32,
+    pub max_samples: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Submit {
+    /// Ask for confirmation before submitting an answer.
+    pub confirm: bool,
+    /// Refuse to submit answers that contradict stored verdicts, e.g. a wrong answer or
+    /// an answer above one that was already judged too high.
+    pub check_known_answers: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Checks {
+    /// Convert Windows line endings (CRLF) in inputs and examples to LF.
+    pub normalize_line_endings: bool,
+    /// Warn about whitespace at the end of inputs, e.g. from copy & paste.
+    /// Off by default: column-aligned puzzle data may end in spaces.
+    pub trailing_whitespace: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            server_utc_offset: -5,
+            session_file: None,
+            paths: Paths {
+                inputs: "data/inputs".into(),
+                examples: "data/examples".into(),
+                puzzles: "data/puzzles".into(),
+                timings: "data/timings.json".into(),
+                answers: "data/answers.json".into(),
+                templates: "templates".into(),
+            },
+            readme: Readme {
+                path: "README.md".into(),
+                benchmarks_marker: "<!--- benchmarking table --->".into(),
+                stars_marker: "<!--- advent_readme_stars table --->".into(),
+            },
+            bench: Bench {
+                duration: Duration::from_secs(1),
+                min_samples: 10,
+                max_samples: 10000,
+            },
+            submit: Submit {
+                confirm: false,
+                check_known_answers: true,
+            },
+            checks: Checks {
+                normalize_line_endings: true,
+                trailing_whitespace: false,
+            },
+        }
+    }
+}
+
+impl Config {
+    /// Read the configuration from `aoc.toml` (or the file set in `AOC_CONFIG`) and apply
+    /// environment overrides. A missing file results in the default configuration.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| CONFIG_FILE_PATH.into());
+
+        let table = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse::<toml::Table>()
+                .map_err(|e| ConfigError::Parser(e.to_string()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => toml::Table::new(),
+            Err(e) => return Err(ConfigError::IO(e)),
+        };
+
+        Self::from_table(&table, |key| env::var(key).ok())
+    }
+
+    fn from_table(
+        table: &toml::Table,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ConfigError> {
+        let source = Source { table, env };
+        let default = Self::default();
+
+        let config = Self {
+            year: source.get(None, "year")?.or(default.year),
+            server_utc_offset: source
+                .get(None, "server_utc_offset")?
+                .unwrap_or(default.server_utc_offset),
+            session_file: source.get(None, "session_file")?.or(default.session_file),
+            paths: Paths {
+                inputs: source
+                    .get(Some("paths"), "inputs")?
+                    .unwrap_or(default.paths.inputs),
+                examples: source
+                    .get(Some("paths"), "examples")?
+                    .unwrap_or(default.paths.examples),
+                puzzles: source
+                    .get(Some("paths"), "puzzles")?
+                    .unwrap_or(default.paths.puzzles),
+                timings: source
+                    .get(Some("paths"), "timings")?
+                    .unwrap_or(default.paths.timings),
+                answers: source
+                    .get(Some("paths"), "answers")?
+                    .unwrap_or(default.paths.answers),
+                templates: source
+                    .get(Some("paths"), "templates")?
+                    .unwrap_or(default.paths.templates),
+            },
+            readme: Readme {
+                path: source
+                    .get(Some("readme"), "path")?
+                    .unwrap_or(default.readme.path),
+                benchmarks_marker: source
+                    .get(Some("readme"), "benchmarks_marker")?
+                    .unwrap_or(default.readme.benchmarks_marker),
+                stars_marker: source
+                    .get(Some("readme"), "stars_marker")?
+                    .unwrap_or(default.readme.stars_marker),
+            },
+            bench: Bench {
+                duration: source
+                    .get(Some("bench"), "duration_ms")?
+                    .map_or(default.bench.duration, Duration::from_millis),
+                min_samples: source
+                    .get(Some("bench"), "min_samples")?
+                    .unwrap_or(default.bench.min_samples),
+                max_samples: source
+                    .get(Some("bench"), "max_samples")?
+                    .unwrap_or(default.bench.max_samples),
+            },
+            submit: Submit {
+                confirm: source
+                    .get(Some("submit"), "confirm")?
+                    .unwrap_or(default.submit.confirm),
+                check_known_answers: source
+                    .get(Some("submit"), "check_known_answers")?
+                    .unwrap_or(default.submit.check_known_answers),
+            },
+            checks: Checks {
+                normalize_line_endings: source
+                    .get(Some("checks"), "normalize_line_endings")?
+                    .unwrap_or(default.checks.normalize_line_endings),
+                trailing_whitespace: source
+                    .get(Some("checks"), "trailing_whitespace")?
+                    .unwrap_or(default.checks.trailing_whitespace),
+            },
+        };
+
+        if config.bench.min_samples > config.bench.max_samples {
+            return Err(ConfigError::Inconsistent(format!(
+                "`bench.min_samples` ({}) is larger than `bench.max_samples` ({})",
+                config.bench.min_samples, config.bench.max_samples
+            )));
+        }
+
+        Ok(config)
+    }
+
+    /// Path of `file_name` in a data `folder`, e.g. `data/inputs/01.txt`.
+    /// Folders without a configured path are looked up in `data/`.
+    pub fn data_file(&self, folder: &str, file_name: &str) -> PathBuf {
+        let dir = match folder {
+            "inputs" => &self.paths.inputs,
+            "examples" => &self.paths.examples,
+            "puzzles" => &self.paths.puzzles,
+            _ => return Path::new("data").join(folder).join(file_name),
+        };
+        dir.join(file_name)
+    }
+
+    pub fn input_path(&self, day: Day) -> PathBuf {
+        self.paths.inputs.join(format!("{day}.txt"))
+    }
+
+    pub fn example_path(&self, day: Day) -> PathBuf {
+        self.paths.examples.join(format!("{day}.txt"))
+    }
+
+    pub fn puzzle_path(&self, day: Day) -> PathBuf {
+        self.paths.puzzles.join(format!("{day}.md"))
+    }
+}
+
+/// Returns the project configuration, loading it on first access.
+/// Exits the process if the configuration is invalid.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::load().unwrap_or_else(|e| {
+            eprintln!("Failed to load configuration: {e}");
+            process::exit(1);
+        })
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Parser(String),
+    InvalidValue(String),
+    /// Values that are valid on their own but contradict each other.
+    Inconsistent(String),
+    IO(io::Error),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Parser(e) => write!(f, "{CONFIG_FILE_PATH} is not a valid TOML file: {e}"),
+            ConfigError::InvalidValue(key) => write!(f, "invalid value for `{key}`."),
+            ConfigError::Inconsistent(reason) => write!(f, "{reason}."),
+            ConfigError::IO(e) => write!(f, "could not read {CONFIG_FILE_PATH}: {e}"),
+        }
+    }
+}
+
+/// A value that can be read from the config file as well as from an environment variable.
+trait ConfigValue: Sized {
+    fn from_toml(value: &toml::Value) -> Option<Self>;
+    fn from_env(value: &str) -> Option<Self>;
+}
+
+impl ConfigValue for String {
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_str().map(Into::into)
+    }
+
+    fn from_env(value: &str) -> Option<Self> {
+        Some(value.into())
+    }
+}
+
+impl ConfigValue for PathBuf {
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_str().map(expand_home)
+    }
+
+    fn from_env(value: &str) -> Option<Self> {
+        Some(expand_home(value))
+    }
+}
+
+impl ConfigValue for bool {
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_bool()
+    }
+
+    fn from_env(value: &str) -> Option<Self> {
+        match value {
+            "1" | "true" => Some(true),
+            "0" | "false" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_config_value_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl ConfigValue for $t {
+                fn from_toml(value: &toml::Value) -> Option<Self> {
+                    value.as_integer().and_then(|x| x.try_into().ok())
+                }
+
+                fn from_env(value: &str) -> Option<Self> {
+                    <$t>::from_str(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_config_value_for_int!(u16, u32, u64, i32);
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), env::var_os("HOME")) {
+        (Some(rest), Some(home)) => Path::new(&home).join(rest),
+        _ => path.into(),
+    }
+}
+
+struct Source<'a, E: Fn(&str) -> Option<String>> {
+    table: &'a toml::Table,
+    env: E,
+}
+
+impl<E: Fn(&str) -> Option<String>> Source<'_, E> {
+    fn get<T: ConfigValue>(
+        &self,
+        section: Option<&str>,
+        key: &str,
+    ) -> Result<Option<T>, ConfigError> {
+        let name = match section {
+            Some(section) => format!("{section}.{key}"),
+            None => key.to_string(),
+        };
+
+        let env_name = format!("AOC_{}", name.replace('.', "_").to_uppercase());
+        if let Some(value) = (self.env)(&env_name) {
+            return T::from_env(&value)
+                .map(Some)
+                .ok_or(ConfigError::InvalidValue(env_name));
+        }
+
+        let table = match section {
+            Some(section) => match self.table.get(section) {
+                Some(value) => value
+                    .as_table()
+                    .ok_or_else(|| ConfigError::InvalidValue(section.into()))?,
+                None => return Ok(None),
+            },
+            None => self.table,
+        };
+
+        table
+            .get(key)
+            .map(|value| T::from_toml(value).ok_or(ConfigError::InvalidValue(name)))
+            .transpose()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::Config;
+
+    fn load(toml: &str, env: &[(&str, &str)]) -> Config {
+        let table = toml.parse::<toml::Table>().unwrap();
+        Config::from_table(&table, |key| {
+            env.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn uses_defaults_for_empty_file() {
+        let config = load("", &[]);
+        assert_eq!(config.year, None);
+        assert_eq!(config.paths.inputs, PathBuf::from("data/inputs"));
+        assert_eq!(config.bench.duration, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn reads_values_from_file() {
+        let config = load(
+            "year = 2023\n[paths]\ninputs = \"inputs\"\n[bench]\nduration_ms = 500\n",
+            &[],
+        );
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.paths.inputs, PathBuf::from("inputs"));
+        assert_eq!(config.paths.examples, PathBuf::from("data/examples"));
+        assert_eq!(config.bench.duration, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn prefers_environment_overrides() {
+        let config = load(
+            "year = 2023\n[submit]\nconfirm = false\n",
+            &[("AOC_YEAR", "2024"), ("AOC_SUBMIT_CONFIRM", "true")],
+        );
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.submit.confirm, true);
+    }
+
+    #[test]
+    fn rejects_inverted_sample_range() {
+        let table = "[bench]\nmin_samples = 100\n".parse::<toml::Table>().unwrap();
+        let env = |key: &str| (key == "AOC_BENCH_MAX_SAMPLES").then(|| "50".to_string());
+        assert!(matches!(
+            Config::from_table(&table, env),
+            Err(super::ConfigError::Inconsistent(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_values() {
+        load("year = \"last year\"", &[]);
+    }
+}
@@ -5,16 +5,13 @@ use std::str::FromStr;
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
-
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
 /// # Display
 /// This value displays as a two digit number.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::Day;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
@@ -41,7 +38,7 @@ impl Day {
 impl Day {
     /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let offset = FixedOffset::east_opt(super::config().server_utc_offset * 3600)?;
         let today = Utc::now().with_timezone(&offset);
         if today.month() == 12 && today.day() <= 25 {
             Self::new(u8::try_from(today.day()).ok()?)
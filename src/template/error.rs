@@ -0,0 +1,152 @@
+/// Errors returned by the template commands.
+use std::{fmt::Display, io, process::ExitStatus};
+
+use crate::template::{
+    Day, ReadmeError, aoc_cli::AocCommandError, commands::scaffold::ScaffoldError,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// aoc-cli is not installed or cannot be called.
+    AocCliMissing,
+    AocCli(AocCommandError),
+    /// `today` was run outside of the advent calendar.
+    NotAdventDay,
+    Scaffold(ScaffoldError),
+    /// A child command, e.g. `cargo run`, could not be spawned or awaited.
+    Command {
+        command: String,
+        source: io::Error,
+    },
+    /// The output of a solution could not be collected.
+    Solution {
+        day: Day,
+        source: io::Error,
+    },
+    /// The solution for a day did not compile.
+    BuildFailed {
+        day: Day,
+    },
+    /// The input of a day is missing or empty, so its solution did not run.
+    InputMissing {
+        day: Day,
+    },
+    /// The solution for a day panicked or exited with a non-zero status.
+    SolutionFailed {
+        day: Day,
+        status: ExitStatus,
+    },
+    /// The tests for a day failed or did not compile.
+    TestsFailed {
+        day: Day,
+        status: ExitStatus,
+    },
+    /// A submitted answer was judged wrong.
+    WrongAnswer {
+        day: Day,
+    },
+    /// The saved puzzle description of a day could not be read.
+    Puzzle {
+        day: Day,
+        source: io::Error,
+    },
+    Timings(io::Error),
+    /// The terminal could not be set up or read from, e.g. for the dashboard.
+    Terminal(io::Error),
+    /// A table in the readme could not be updated, e.g. `benchmarks` or `stars`.
+    Readme {
+        table: &'static str,
+        source: ReadmeError,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AocCliMissing => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            Error::AocCli(e) => write!(f, "failed to call aoc-cli: {e}"),
+            Error::NotAdventDay => write!(
+                f,
+                "`today` command can only be run between the 1st and the 25th of december. Please use `scaffold` with a specific day."
+            ),
+            Error::Scaffold(e) => write!(f, "{e}"),
+            Error::Command { command, source } => {
+                write!(f, "failed to run `{command}`: {source}")
+            }
+            Error::Solution { day, source } => {
+                write!(f, "failed to run solution for day {day}: {source}")
+            }
+            Error::BuildFailed { day } => write!(f, "solution for day {day} failed to build."),
+            Error::InputMissing { day } => write!(f, "input for day {day} is missing or empty."),
+            Error::SolutionFailed { day, status } => {
+                write!(f, "solution for day {day} failed ({status}).")
+            }
+            Error::TestsFailed { day, status } => {
+                write!(f, "tests for day {day} failed ({status}).")
+            }
+            Error::WrongAnswer { day } => write!(f, "answer for day {day} was judged wrong."),
+            Error::Puzzle { day, source } => {
+                write!(f, "failed to read puzzle for day {day}: {source}")
+            }
+            Error::Timings(e) => write!(f, "failed to store timings: {e}"),
+            Error::Terminal(e) => write!(f, "terminal error: {e}"),
+            Error::Readme { table, source } => {
+                write!(f, "failed to store updated {table} in the readme: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    /// The exit code of the `advent_of_code` binary for this error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::BuildFailed { .. } => exit_code::BUILD_FAILED,
+            Error::InputMissing { .. } => exit_code::INPUT_MISSING,
+            Error::SolutionFailed { .. } => exit_code::SOLUTION_FAILED,
+            Error::WrongAnswer { .. } => exit_code::WRONG_ANSWER,
+            _ => exit_code::FAILURE,
+        }
+    }
+
+    /// Classify the exit status of a solution binary run with `cargo run`.
+    pub(crate) fn from_solution_status(day: Day, status: ExitStatus) -> Result<(), Self> {
+        match status.code() {
+            _ if status.success() => Ok(()),
+            Some(exit_code::WRONG_ANSWER) => Err(Error::WrongAnswer { day }),
+            Some(exit_code::INPUT_MISSING) => Err(Error::InputMissing { day }),
+            _ => Err(Error::SolutionFailed { day, status }),
+        }
+    }
+}
+
+/// Exit codes shared by the `advent_of_code` binary and the solution binaries.
+pub mod exit_code {
+    /// Generic failure, e.g. invalid arguments or a missing aoc-cli.
+    pub const FAILURE: i32 = 1;
+    /// The solution did not compile.
+    pub const BUILD_FAILED: i32 = 2;
+    /// The solution panicked or exited with an unexpected status.
+    pub const SOLUTION_FAILED: i32 = 3;
+    /// A submitted answer was judged wrong.
+    pub const WRONG_ANSWER: i32 = 4;
+    /// The input file is missing or empty.
+    pub const INPUT_MISSING: i32 = 5;
+}
+
+impl From<AocCommandError> for Error {
+    fn from(e: AocCommandError) -> Self {
+        Error::AocCli(e)
+    }
+}
+
+impl From<ScaffoldError> for Error {
+    fn from(e: ScaffoldError) -> Self {
+        Error::Scaffold(e)
+    }
+}
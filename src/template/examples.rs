@@ -0,0 +1,373 @@
+/// Reads all example files of a day, e.g. `11-1.txt` and `11-2.txt`.
+///
+/// Examples are the files in the examples folder named after the day, either `<day>.txt` or
+/// `<day>-<name>.txt`. Expected answers and the parts an example applies to are read from an
+/// optional `<day>.toml` next to them, with one table per example:
+///
+/// ```toml
+/// ["11-1"]
+/// part_one = 5
+///
+/// ["11-2"]
+/// part_two = 2
+/// ```
+///
+/// Any other key is a parameter of the example, see [`Input::param`].
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::{Day, Input, InputError, config, input};
+
+#[derive(Clone, Debug)]
+pub struct Example {
+    /// The file name without extension, e.g. `11-1`.
+    pub name: String,
+    pub path: PathBuf,
+    pub input: Input,
+    /// The parts the example applies to.
+    pub parts: Vec<u8>,
+    /// The expected answers for part one and part two, if known.
+    pub expected: [Option<String>; 2],
+}
+
+impl Example {
+    pub fn applies_to(&self, part: u8) -> bool {
+        self.parts.contains(&part)
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.expected
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Metadata(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Input(e) => write!(f, "{e}"),
+            Error::Metadata(e) => write!(f, "invalid example metadata: {e}"),
+            Error::IO(e) => write!(f, "could not list examples: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<InputError> for Error {
+    fn from(e: InputError) -> Self {
+        Error::Input(e)
+    }
+}
+
+/// Names of the example files of a day, sorted.
+fn example_names(day: Day) -> Result<Vec<String>, Error> {
+    let entries = match fs::read_dir(&config().paths.examples) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(Error::IO(e)),
+    };
+
+    let day = day.to_string();
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            let name = path.file_stem()?.to_str()?;
+            let is_day = name == day || name.strip_prefix(&day)?.starts_with('-');
+            is_day.then(|| name.to_string())
+        })
+        .collect();
+
+    names.sort();
+    Ok(names)
+}
+
+fn read_metadata(day: Day) -> Result<toml::Table, Error> {
+    let path = config().paths.examples.join(format!("{day}.toml"));
+
+    match fs::read_to_string(&path) {
+        Ok(contents) => contents
+            .parse::<toml::Table>()
+            .map_err(|e| Error::Metadata(format!("{}: {e}", path.display()))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(toml::Table::new()),
+        Err(e) => Err(Error::IO(e)),
+    }
+}
+
+/// The parts an example applies to. Set with `parts = [1]`, otherwise derived from the
+/// name: `11-1` applies to part one, `11-2` to part two and any other example to both.
+fn parse_parts(day: Day, name: &str, metadata: &toml::Table) -> Result<Vec<u8>, Error> {
+    if let Some(parts) = metadata.get("parts") {
+        let invalid = || Error::Metadata(format!("`{name}.parts` must be a list of 1 and/or 2."));
+        return parts
+            .as_array()
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|part| match part.as_integer() {
+                Some(1) => Ok(1),
+                Some(2) => Ok(2),
+                _ => Err(invalid()),
+            })
+            .collect();
+    }
+
+    match name.strip_prefix(&format!("{day}-")) {
+        Some("1") => Ok(vec![1]),
+        Some("2") => Ok(vec![2]),
+        _ => Ok(vec![1, 2]),
+    }
+}
+
+/// Keys of the example metadata that are not parameters.
+const RESERVED_KEYS: [&str; 3] = ["parts", "part_one", "part_two"];
+
+fn parse_answer(name: &str, key: &str, metadata: &toml::Table) -> Result<Option<String>, Error> {
+    match metadata.get(key) {
+        None => Ok(None),
+        Some(toml::Value::Integer(x)) => Ok(Some(x.to_string())),
+        Some(toml::Value::String(x)) => Ok(Some(x.clone())),
+        Some(_) => Err(Error::Metadata(format!(
+            "`{name}.{key}` must be an integer or a string."
+        ))),
+    }
+}
+
+/// Read all examples of a day.
+pub fn all(day: Day) -> Result<Vec<Example>, Error> {
+    let metadata = read_metadata(day)?;
+    let empty = toml::Table::new();
+
+    example_names(day)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let file_name = format!("{name}.txt");
+            let meta = match metadata.get(&name) {
+                Some(value) => value
+                    .as_table()
+                    .ok_or_else(|| Error::Metadata(format!("`{name}` must be a table.")))?,
+                None => &empty,
+            };
+
+            let params = meta
+                .iter()
+                .filter(|(key, _)| !RESERVED_KEYS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+
+            Ok(Example {
+                path: config().data_file("examples", &file_name),
+                input: Input::example(
+                    input::read("examples", day, &file_name)?,
+                    index,
+                    name.clone(),
+                    params,
+                ),
+                parts: parse_parts(day, &name, meta)?,
+                expected: [
+                    parse_answer(&name, "part_one", meta)?,
+                    parse_answer(&name, "part_two", meta)?,
+                ],
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Read the examples of a day that apply to `part`.
+pub fn for_part(day: Day, part: u8) -> Result<Vec<Example>, Error> {
+    Ok(all(day)?
+        .into_iter()
+        .filter(|example| example.applies_to(part))
+        .collect())
+}
+
+/// Run `func` against the example `name`, e.g. `11-1`, and compare it with its expected answer.
+/// Skipped if the example does not apply to `part` or has no expected answer for it.
+pub fn check_example<T: Display>(
+    day: Day,
+    part: u8,
+    name: &str,
+    func: impl Fn(&Input) -> Option<T>,
+) {
+    let example = all(day)
+        .unwrap_or_else(|e| panic!("{e}"))
+        .into_iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("example `{name}` does not exist."));
+
+    if !example.applies_to(part) {
+        println!("example `{name}` does not apply to part {part}, skipped.");
+        return;
+    }
+    let Some(expected) = example.expected(part) else {
+        println!("example `{name}`: no expected answer for part {part}, skipped.");
+        return;
+    };
+
+    let result = func(&example.input).map(|result| result.to_string());
+    assert_eq!(
+        result.as_deref(),
+        Some(expected),
+        "example `{name}`, part {part}"
+    );
+}
+
+/// Run `func` against every example of the day that applies to `part`, and fail if any of them
+/// gives a wrong answer or is missing from `listed`, so a new example file is never silently
+/// left untested.
+pub fn check_every_example<T: Display>(
+    day: Day,
+    part: u8,
+    listed: &[String],
+    func: impl Fn(&Input) -> Option<T>,
+) {
+    let examples = all(day).unwrap_or_else(|e| panic!("{e}"));
+
+    let wrong: Vec<String> = examples
+        .iter()
+        .filter(|example| example.applies_to(part))
+        .filter_map(|example| {
+            let expected = example.expected(part)?;
+            let result = func(&example.input).map(|result| result.to_string());
+            (result.as_deref() != Some(expected))
+                .then(|| format!("`{}`: expected {expected}, got {result:?}", example.name))
+        })
+        .collect();
+    assert!(
+        wrong.is_empty(),
+        "part {part} fails examples {}",
+        wrong.join(", ")
+    );
+
+    let unlisted: Vec<&str> = examples
+        .iter()
+        .map(|example| example.name.as_str())
+        .filter(|name| !listed.iter().any(|listed| listed == name))
+        .collect();
+    assert!(
+        unlisted.is_empty(),
+        "examples {unlisted:?} are not listed in `example_tests!`, add them to get a test each."
+    );
+}
+
+/// Generates one test per part and example that checks the part against the answer in the
+/// example metadata, so a failing example names its file and does not hide the others.
+/// Examples are listed like the cases of [`solution_tests!`](crate::solution_tests):
+/// `example` is the day's default example, `<name>` reads `<day>-<name>.txt`, and
+/// `<name>("<file>")` reads an example with any name.
+///
+/// The tests are named after the part and the example, e.g. `tests::part_one::first`.
+/// Every part also gets an `every_example` test that runs all example files of the day and
+/// fails for files that are not listed, see [`check_every_example`].
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     advent_of_code::example_tests!(part_one, part_two {
+///         first("11-1"),
+///         second("11-2"),
+///     });
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (@part part_one) => { 1 };
+    (@part part_two) => { 2 };
+
+    (@name $case:ident $name:literal) => { $name.to_string() };
+    (@name $case:ident) => {
+        $crate::template::testing::example_name(super::DAY, stringify!($case))
+    };
+
+    (@tests $func:ident { $($case:ident $(($name:literal))?),* $(,)? }) => {
+        mod $func {
+            $(
+                #[test]
+                fn $case() {
+                    $crate::template::examples::check_example(
+                        super::DAY,
+                        $crate::example_tests!(@part $func),
+                        &$crate::example_tests!(@name $case $($name)?),
+                        |input: &$crate::template::Input| super::$func(input),
+                    );
+                }
+            )*
+
+            #[test]
+            fn every_example() {
+                $crate::template::examples::check_every_example(
+                    super::DAY,
+                    $crate::example_tests!(@part $func),
+                    &[$($crate::example_tests!(@name $case $($name)?)),*],
+                    |input: &$crate::template::Input| super::$func(input),
+                );
+            }
+        }
+    };
+
+    (@each $examples:tt $(,)?) => {};
+    (@each $examples:tt, $func:ident $($rest:tt)*) => {
+        $crate::example_tests!(@tests $func $examples);
+        $crate::example_tests!(@each $examples $($rest)*);
+    };
+
+    ($($func:ident),+ { $($examples:tt)* }) => {
+        $crate::example_tests!(@each { $($examples)* } $(, $func)+);
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_answer, parse_parts};
+    use crate::day;
+
+    #[test]
+    fn derives_parts_from_names() {
+        let empty = toml::Table::new();
+        assert_eq!(parse_parts(day!(11), "11-1", &empty).unwrap(), vec![1]);
+        assert_eq!(parse_parts(day!(11), "11-2", &empty).unwrap(), vec![2]);
+        assert_eq!(parse_parts(day!(11), "11", &empty).unwrap(), vec![1, 2]);
+        assert_eq!(
+            parse_parts(day!(11), "11-large", &empty).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn reads_metadata() {
+        let metadata = "parts = [2]\npart_two = 42\n"
+            .parse::<toml::Table>()
+            .unwrap();
+        assert_eq!(parse_parts(day!(1), "01-1", &metadata).unwrap(), vec![2]);
+        assert_eq!(
+            parse_answer("01-1", "part_two", &metadata)
+                .unwrap()
+                .as_deref(),
+            Some("42")
+        );
+        assert_eq!(parse_answer("01-1", "part_one", &metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_metadata() {
+        let metadata = "parts = [3]\npart_one = 1.5\n"
+            .parse::<toml::Table>()
+            .unwrap();
+        assert!(parse_parts(day!(1), "01", &metadata).is_err());
+        assert!(parse_answer("01", "part_one", &metadata).is_err());
+    }
+}
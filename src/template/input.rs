@@ -0,0 +1,275 @@
+/// Reads puzzle inputs and examples and checks them for common mistakes before solving,
+/// e.g. a missing download or Windows line endings.
+use std::{
+    fmt::Display,
+    fs, io,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, config};
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { path: PathBuf, hint: String },
+    Empty { path: PathBuf, hint: String },
+    IO { path: PathBuf, source: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { path, hint } => {
+                write!(f, "\"{}\" does not exist, {hint}", path.display())
+            }
+            InputError::Empty { path, hint } => {
+                write!(f, "\"{}\" is empty, {hint}", path.display())
+            }
+            InputError::IO { path, source } => {
+                write!(f, "could not read \"{}\": {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// What to do about a missing or empty file in `folder`.
+fn hint(folder: &str, day: Day) -> String {
+    match folder {
+        "inputs" => format!("run `cargo download {day}` to download your input."),
+        "examples" => "copy the example from the puzzle description into it.".into(),
+        _ => "check that it was created.".into(),
+    }
+}
+
+fn has_crlf(contents: &str) -> bool {
+    contents.contains("\r\n")
+}
+
+/// Whether there is whitespace after the last line, besides a single newline.
+fn has_trailing_whitespace(contents: &str) -> bool {
+    let contents = contents.strip_suffix('\n').unwrap_or(contents);
+    contents.trim_end() != contents
+}
+
+/// Read a file in a data folder and check it:
+///  1. missing and empty files are errors with a hint how to fix them.
+///  2. Windows line endings are converted to `\n` if `checks.normalize_line_endings` is set.
+///  3. trailing whitespace in inputs is reported as a warning if `checks.trailing_whitespace` is set.
+///     Examples are copied from the puzzle description and may end in spaces on purpose.
+pub fn read(folder: &str, day: Day, file_name: &str) -> Result<String, InputError> {
+    let path = config().data_file(folder, file_name);
+    let checks = &config().checks;
+
+    let mut contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(InputError::Missing {
+                path,
+                hint: hint(folder, day),
+            });
+        }
+        Err(source) => return Err(InputError::IO { path, source }),
+    };
+
+    if contents.trim().is_empty() {
+        return Err(InputError::Empty {
+            path,
+            hint: hint(folder, day),
+        });
+    }
+
+    if has_crlf(&contents) {
+        if checks.normalize_line_endings {
+            contents = contents.replace("\r\n", "\n");
+        } else {
+            warn(&path, "has Windows line endings (CRLF).");
+        }
+    }
+
+    if checks.trailing_whitespace && folder != "examples" && has_trailing_whitespace(&contents) {
+        warn(
+            &path,
+            "ends with whitespace, which may be left over from copy & paste.",
+        );
+    }
+
+    Ok(contents)
+}
+
+fn warn(path: &Path, message: &str) {
+    eprintln!("Warning: \"{}\" {message}", path.display());
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Where an [`Input`] comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Real,
+    Example {
+        /// Position of the example among the day's examples, sorted by name.
+        index: usize,
+        /// The file name of the example without extension, e.g. `11-1`.
+        name: String,
+    },
+}
+
+/// A puzzle input together with its context.
+///
+/// Dereferences to `str`, so it can be passed to solutions taking `&str`. Solutions that
+/// need to behave differently for examples take `&Input` instead, e.g. to read parameters
+/// from the example metadata in `data/examples/<day>.toml`:
+///
+/// ```ignore
+/// pub fn part_one(input: &Input) -> Option<u64> {
+///     let connection_limit = input.param("connection_limit").unwrap_or(1000);
+///     // ...
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Input {
+    text: String,
+    kind: InputKind,
+    params: toml::Table,
+}
+
+impl Input {
+    /// The real puzzle input, without parameters.
+    pub fn real(text: String) -> Self {
+        Self {
+            text,
+            kind: InputKind::Real,
+            params: toml::Table::new(),
+        }
+    }
+
+    pub fn example(text: String, index: usize, name: String, params: toml::Table) -> Self {
+        Self {
+            text,
+            kind: InputKind::Example { index, name },
+            params,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn kind(&self) -> &InputKind {
+        &self.kind
+    }
+
+    pub fn is_example(&self) -> bool {
+        matches!(self.kind, InputKind::Example { .. })
+    }
+
+    pub fn example_index(&self) -> Option<usize> {
+        match self.kind {
+            InputKind::Example { index, .. } => Some(index),
+            InputKind::Real => None,
+        }
+    }
+
+    pub fn example_name(&self) -> Option<&str> {
+        match &self.kind {
+            InputKind::Example { name, .. } => Some(name),
+            InputKind::Real => None,
+        }
+    }
+
+    /// A custom parameter of the input, if it is set and has the requested type.
+    pub fn param<T: Param>(&self, key: &str) -> Option<T> {
+        self.params.get(key).and_then(T::from_toml)
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.text
+    }
+}
+
+/// A value that can be read from the parameters of an [`Input`].
+pub trait Param: Sized {
+    fn from_toml(value: &toml::Value) -> Option<Self>;
+}
+
+impl Param for String {
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_str().map(Into::into)
+    }
+}
+
+impl Param for bool {
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl Param for f64 {
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value
+            .as_float()
+            .or_else(|| value.as_integer().map(|x| x as f64))
+    }
+}
+
+macro_rules! impl_param_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Param for $t {
+                fn from_toml(value: &toml::Value) -> Option<Self> {
+                    value.as_integer().and_then(|x| x.try_into().ok())
+                }
+            }
+        )*
+    };
+}
+
+impl_param_for_int!(u8, u16, u32, u64, usize, i32, i64);
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Input, InputError, has_crlf, has_trailing_whitespace, read};
+    use crate::day;
+
+    #[test]
+    fn detects_crlf() {
+        assert!(has_crlf("1\r\n2\r\n"));
+        assert!(!has_crlf("1\n2\n"));
+    }
+
+    #[test]
+    fn detects_trailing_whitespace() {
+        assert!(!has_trailing_whitespace("1\n2\n"));
+        assert!(!has_trailing_whitespace("1\n2"));
+        assert!(has_trailing_whitespace("1\n2\n\n"));
+        assert!(has_trailing_whitespace("1\n2  \n"));
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let error = read("inputs", day!(25), "missing.txt").unwrap_err();
+        assert!(matches!(error, InputError::Missing { .. }));
+        assert!(error.to_string().contains("cargo download 25"));
+    }
+
+    #[test]
+    fn reads_params() {
+        let params = "limit = 10\nname = \"a\"".parse::<toml::Table>().unwrap();
+        let input = Input::example("1\n2".into(), 0, "08".into(), params);
+
+        assert_eq!(input.param::<usize>("limit"), Some(10));
+        assert_eq!(input.param::<String>("name").as_deref(), Some("a"));
+        assert_eq!(input.param::<bool>("limit"), None);
+        assert_eq!(input.example_name(), Some("08"));
+        assert_eq!(input.lines().count(), 2);
+        assert!(!Input::real("1".into()).is_example());
+    }
+}
@@ -0,0 +1,250 @@
+/// Renders the puzzle descriptions saved by aoc-cli in the terminal.
+/// Only the subset of markdown produced from the advent of code html is supported.
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_WHITE: &str = "\x1b[97m";
+
+/// Control characters that replace the brackets of links before rendering inline text.
+const LINK_START: char = '\u{1}';
+const LINK_END: char = '\u{2}';
+
+/// The inline styles active at a position in a line.
+#[derive(Clone, Copy, Default)]
+struct Style {
+    code: bool,
+    emphasis: bool,
+    strong: bool,
+    link: bool,
+    /// Code spans are answers, e.g. in "Your puzzle answer was `42`."
+    answer: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut s = String::from(ANSI_RESET);
+        if self.code && self.answer {
+            s.push_str(ANSI_BOLD);
+            s.push_str(ANSI_YELLOW);
+        } else if self.code {
+            s.push_str(ANSI_CYAN);
+        }
+        if self.emphasis || self.strong {
+            s.push_str(ANSI_BOLD);
+            if !self.code {
+                s.push_str(ANSI_WHITE);
+            }
+        }
+        if self.link {
+            s.push_str(ANSI_UNDERLINE);
+        }
+        s
+    }
+}
+
+/// Render a markdown document with ANSI escape codes.
+pub fn render(markdown: &str) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut lines = markdown.lines().peekable();
+    let mut in_code_block = false;
+
+    while let Some(line) = lines.next() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if line.trim().is_empty() && !in_code_block {
+            out.push(String::new());
+        } else if in_code_block {
+            out.push(format!("    {ANSI_CYAN}{line}{ANSI_RESET}"));
+        } else if let Some(code) = line.strip_prefix("    ") {
+            // only the indent marking the block is removed, the code may be indented itself.
+            out.push(format!("    {ANSI_CYAN}{code}{ANSI_RESET}"));
+        } else if !line.trim().is_empty() && lines.peek().is_some_and(|l| is_underline(l)) {
+            lines.next();
+            out.push(heading(line));
+        } else if line.starts_with('#') {
+            out.push(heading(line.trim_start_matches('#')));
+        } else if is_underline(line) {
+            // a horizontal rule.
+            out.push(String::new());
+        } else if let Some(item) = line.strip_prefix("* ").or_else(|| line.strip_prefix("- ")) {
+            out.push(format!("  • {}", render_inline(item, false)));
+        } else {
+            let answer = line.starts_with("Your puzzle answer was");
+            out.push(render_inline(line, answer));
+        }
+    }
+
+    out.join("\n")
+}
+
+/// A setext heading underline, e.g. `----------`.
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+fn heading(text: &str) -> String {
+    let text = text.trim().trim_start_matches('\\');
+    format!("{ANSI_BOLD}{ANSI_GREEN}{text}{ANSI_RESET}")
+}
+
+/// Replace `[label](url)` with the label wrapped in [`LINK_START`] and [`LINK_END`].
+fn strip_links(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('[') {
+        let link = rest[start + 1..]
+            .split_once("](")
+            .and_then(|(label, after)| {
+                let (_, remainder) = after.split_once(')')?;
+                (!label.contains('[')).then_some((label, remainder))
+            });
+
+        match link {
+            Some((label, remainder)) if start == 0 || !rest[..start].ends_with('\\') => {
+                out.push_str(&rest[..start]);
+                out.push(LINK_START);
+                out.push_str(label);
+                out.push(LINK_END);
+                rest = remainder;
+            }
+            _ => {
+                out.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn render_inline(text: &str, answer: bool) -> String {
+    let text = strip_links(text);
+    let chars: Vec<char> = text.chars().collect();
+    let mut style = Style {
+        answer,
+        ..Style::default()
+    };
+    let mut out = String::with_capacity(text.len());
+
+    let mut i = 0;
+    while i < chars.len() {
+        let prev = i.checked_sub(1).map(|j| chars[j]);
+        let next = chars.get(i + 1).copied();
+
+        match chars[i] {
+            // code spans are literal, so backslashes only escape outside of them.
+            '\\' if next.is_some() && !style.code => {
+                out.extend(next);
+                i += 1;
+            }
+            '`' => {
+                style.code = !style.code;
+                out.push_str(&style.ansi());
+            }
+            '*' if next == Some('*')
+                && (style.strong || chars.get(i + 2).is_some_and(|c| !c.is_whitespace())) =>
+            {
+                style.strong = !style.strong;
+                out.push_str(&style.ansi());
+                i += 1;
+            }
+            // inside code spans, only emphasis wrapping the whole span is styled, e.g. `*42*`.
+            '*' if style.code && prev != Some('`') && next != Some('`') => out.push('*'),
+            '*' if style.emphasis && prev.is_some_and(|c| !c.is_whitespace()) => {
+                style.emphasis = false;
+                out.push_str(&style.ansi());
+            }
+            '*' if !style.emphasis && next.is_some_and(|c| !c.is_whitespace()) => {
+                style.emphasis = true;
+                out.push_str(&style.ansi());
+            }
+            LINK_START | LINK_END => {
+                style.link = chars[i] == LINK_START;
+                out.push_str(&style.ansi());
+            }
+            c => out.push(c),
+        }
+
+        i += 1;
+    }
+
+    out.push_str(ANSI_RESET);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ANSI_RESET, render};
+
+    /// Remove ANSI escape codes from rendered output.
+    fn plain(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn renders_headings() {
+        let rendered = render("\\--- Day 1: Secret Entrance ---\n----------\n\n# Title");
+        assert_eq!(plain(&rendered), "--- Day 1: Secret Entrance ---\n\nTitle");
+    }
+
+    #[test]
+    fn renders_inline_styles() {
+        let rendered =
+            render("The dial starts by pointing at *`50`*, see [here](/2025/day/1) \\*.");
+        assert_eq!(
+            plain(&rendered),
+            "The dial starts by pointing at 50, see here *."
+        );
+    }
+
+    #[test]
+    fn keeps_code_blocks_verbatim() {
+        let rendered = render("```\nL68\n*L30*\n```");
+        assert_eq!(plain(&rendered), "    L68\n    *L30*");
+    }
+
+    #[test]
+    fn keeps_indentation_in_code_blocks() {
+        let rendered = render("```\n123 328\n 45 64\n```\n\n    a\n      b");
+        assert_eq!(plain(&rendered), "    123 328\n     45 64\n\n    a\n      b");
+    }
+
+    #[test]
+    fn highlights_answers() {
+        let rendered = render("Your puzzle answer was `1150`.");
+        assert!(rendered.contains(&format!("{ANSI_RESET}\x1b[1m\x1b[33m1150")));
+        assert_eq!(plain(&rendered), "Your puzzle answer was 1150.");
+    }
+
+    #[test]
+    fn keeps_literal_asterisks() {
+        let rendered = render("2 * 3 is `a*b`");
+        assert_eq!(plain(&rendered), "2 * 3 is a*b");
+    }
+
+    #[test]
+    fn keeps_backslashes_in_code_spans() {
+        let rendered = render("`a\\*b` and a\\*b");
+        assert_eq!(plain(&rendered), "a\\*b and a*b");
+    }
+}
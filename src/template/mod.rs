@@ -1,13 +1,25 @@
-use std::{env, fs};
-
+pub mod answers;
 pub mod aoc_cli;
 pub mod commands;
+pub mod config;
+pub mod examples;
+pub mod property;
 pub mod runner;
+pub mod testing;
 
+pub use config::config;
 pub use day::*;
+pub use error::{Error, exit_code};
+pub use input::{Input, InputError, InputKind, Param};
+pub use readme::Error as ReadmeError;
 
 mod day;
+mod error;
+mod input;
+mod markdown;
+mod readme;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
 mod timings;
 
@@ -16,24 +28,22 @@ pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// Panics with a hint if the file is missing or empty, see [`read_input`].
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    read_input(folder, day).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    input::read(folder, day, &format!("{day}-{part}.txt")).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Reads a text file to a string, checking that it exists and is not empty.
+/// Windows line endings are normalized depending on the `[checks]` section of `aoc.toml`.
+pub fn read_input(folder: &str, day: Day) -> Result<String, InputError> {
+    input::read(folder, day, &format!("{day}.txt"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
@@ -61,8 +71,23 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::read_input("inputs", DAY)
+                .map($crate::template::Input::real)
+                .unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    std::process::exit(match e {
+                        $crate::template::InputError::Missing { .. }
+                        | $crate::template::InputError::Empty { .. } => {
+                            $crate::template::exit_code::INPUT_MISSING
+                        }
+                        _ => $crate::template::exit_code::FAILURE,
+                    });
+                });
+            let verdicts = [$( run_part($func, &input, DAY, $part) ),*];
+
+            if verdicts.iter().flatten().any(|verdict| verdict.is_wrong()) {
+                std::process::exit($crate::template::exit_code::WRONG_ANSWER);
+            }
         }
     };
 }
@@ -0,0 +1,301 @@
+//! Property tests that compare a solution against a slow but obviously correct reference
+//! solver on generated inputs. Failing inputs are shrunk to a minimal counterexample.
+//!
+//! ```ignore
+//! #[test]
+//! fn part_one_matches_simulation() {
+//!     property::check(
+//!         |rng| (0..rng.range(1..=20)).map(|_| rng.range(1..=99).to_string() + "\n").collect(),
+//!         part_one,
+//!         |input| Some(simulate(input)),
+//!     );
+//! }
+//! ```
+
+use std::{
+    env,
+    fmt::Debug,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// A small, seeded random number generator (xorshift64*), so failures are reproducible.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // mix the seed with splitmix64, so similar seeds give unrelated sequences.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // xorshift gets stuck at zero, so the one seed mixing to it is moved.
+        Self { state: z.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A random number in `range`.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        match (end - start).checked_add(1) {
+            Some(span) => start + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// A random element of `items`. Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        let index = self.range(0..=items.len() as u64 - 1);
+        &items[usize::try_from(index).unwrap()]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Number of generated inputs.
+    pub cases: u32,
+    /// Seed of the first case. Can be set with `AOC_PROPERTY_SEED` to reproduce a failure.
+    pub seed: u64,
+    /// Upper bound for the number of shrinking attempts.
+    pub max_shrinks: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            cases: 100,
+            seed: env::var("AOC_PROPERTY_SEED")
+                .ok()
+                .and_then(|seed| seed.parse().ok())
+                .unwrap_or(2025),
+            max_shrinks: 1000,
+        }
+    }
+}
+
+/// The result of running a solver on an input.
+#[derive(Debug, PartialEq)]
+enum Outcome<T> {
+    Returned(T),
+    Panicked(String),
+}
+
+fn run<T>(func: impl Fn(&str) -> T, input: &str) -> Outcome<T> {
+    match panic::catch_unwind(AssertUnwindSafe(|| func(input))) {
+        Ok(result) => Outcome::Returned(result),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Outcome::Panicked(message)
+        }
+    }
+}
+
+/// Whether `solve` disagrees with `reference` on a valid input.
+/// Inputs that make the reference panic are invalid.
+fn fails<T: PartialEq>(
+    input: &str,
+    solve: &impl Fn(&str) -> T,
+    reference: &impl Fn(&str) -> T,
+) -> bool {
+    match run(reference, input) {
+        Outcome::Returned(expected) => run(solve, input) != Outcome::Returned(expected),
+        Outcome::Panicked(_) => false,
+    }
+}
+
+/// Smaller variants of an input: first without chunks of lines, then with halved numbers.
+fn shrink_candidates(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut candidates = vec![];
+
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        for start in (0..lines.len()).step_by(chunk) {
+            let mut shrunk: Vec<&str> = lines[..start].to_vec();
+            shrunk.extend(&lines[(start + chunk).min(lines.len())..]);
+            candidates.push(shrunk.join("\n") + "\n");
+        }
+        chunk /= 2;
+    }
+
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if let Ok(number) = input[start..i].parse::<u64>()
+            && number > 0
+        {
+            for smaller in [0, number / 2, number - 1] {
+                if smaller < number {
+                    candidates.push(format!("{}{smaller}{}", &input[..start], &input[i..]));
+                }
+            }
+        }
+    }
+
+    candidates.dedup();
+    candidates
+}
+
+/// Shrink a failing input as long as a smaller variant still fails.
+fn shrink<T: PartialEq>(
+    input: String,
+    max_shrinks: u32,
+    solve: &impl Fn(&str) -> T,
+    reference: &impl Fn(&str) -> T,
+) -> String {
+    let mut current = input;
+    let mut attempts = 0;
+
+    'shrink: loop {
+        for candidate in shrink_candidates(&current) {
+            if attempts >= max_shrinks {
+                break 'shrink;
+            }
+            attempts += 1;
+
+            if (candidate.len() < current.len() || candidate < current)
+                && fails(&candidate, solve, reference)
+            {
+                current = candidate;
+                continue 'shrink;
+            }
+        }
+        break;
+    }
+
+    current
+}
+
+/// Compare `solve` with `reference` on inputs produced by `generate`, with default [`Options`].
+pub fn check<T: PartialEq + Debug>(
+    generate: impl Fn(&mut Rng) -> String,
+    solve: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) {
+    check_with(&Options::default(), generate, solve, reference);
+}
+
+/// Compare `solve` with `reference` on inputs produced by `generate`.
+/// Panics with a shrunk counterexample if they disagree or `solve` panics.
+pub fn check_with<T: PartialEq + Debug>(
+    options: &Options,
+    generate: impl Fn(&mut Rng) -> String,
+    solve: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) {
+    for case in 0..options.cases {
+        let seed = options.seed.wrapping_add(u64::from(case));
+        let input = generate(&mut Rng::new(seed));
+
+        if let Outcome::Panicked(message) = run(&reference, &input) {
+            panic!("reference panicked on generated input (seed {seed}): {message}\n{input}");
+        }
+
+        if !fails(&input, &solve, &reference) {
+            continue;
+        }
+
+        let shrunk = shrink(input, options.max_shrinks, &solve, &reference);
+        let expected = run(&reference, &shrunk);
+        let actual = run(&solve, &shrunk);
+
+        panic!(
+            "property failed for case {case}, rerun with AOC_PROPERTY_SEED={seed}.\n\
+            shrunk input:\n{shrunk}\n\
+            expected: {expected:?}\n\
+            actual:   {actual:?}"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Options, Rng, check_with, shrink};
+
+    fn sum(input: &str) -> u64 {
+        input.lines().map(|l| l.parse::<u64>().unwrap()).sum()
+    }
+
+    /// Sums all numbers, but ignores numbers above 50.
+    fn broken_sum(input: &str) -> u64 {
+        input
+            .lines()
+            .map(|l| l.parse::<u64>().unwrap())
+            .filter(|x| *x <= 50)
+            .sum()
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        (0..rng.range(1..=10))
+            .map(|_| format!("{}\n", rng.range(0..=99)))
+            .collect()
+    }
+
+    #[test]
+    fn generates_reproducible_numbers() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(1);
+        for _ in 0..100 {
+            let x = a.range(3..=7);
+            assert_eq!(x, b.range(3..=7));
+            assert!((3..=7).contains(&x));
+        }
+    }
+
+    #[test]
+    fn never_gets_stuck_at_zero() {
+        for seed in [0, 1, 0x9E37_79B9_7F4A_7C15, u64::MAX] {
+            let mut rng = Rng::new(seed);
+            let numbers: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+            assert!(numbers.iter().any(|&x| x != 0), "seed {seed}");
+            assert_ne!(numbers[0], numbers[1], "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn passes_for_equal_solvers() {
+        check_with(&Options::default(), generate, sum, |input| {
+            input
+                .lines()
+                .fold(0, |acc, l| acc + l.parse::<u64>().unwrap())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "property failed")]
+    fn fails_for_different_solvers() {
+        check_with(&Options::default(), generate, broken_sum, sum);
+    }
+
+    #[test]
+    fn shrinks_to_minimal_input() {
+        let shrunk = shrink("3\n70\n12\n99\n".into(), 1000, &broken_sum, &sum);
+        assert_eq!(shrunk, "51\n");
+    }
+}
@@ -0,0 +1,70 @@
+/// Module that replaces marker-delimited blocks in the readme.
+/// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::{fmt::Display, fs, io};
+
+use crate::template::config;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => f.write_str(e),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+pub struct TablePosition {
+    pos_start: usize,
+    pos_end: usize,
+}
+
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
+    }
+
+    let pos_start = matches
+        .first()
+        .map(|m| m.0)
+        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+    Ok(TablePosition { pos_start, pos_end })
+}
+
+/// Replace the block delimited by `marker` in `s` with `table`.
+/// `table` is expected to start and end with `marker` itself.
+pub fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+/// Read the readme file, apply `update_content` to it and write it back.
+pub fn update(update_content: impl FnOnce(&mut String) -> Result<(), Error>) -> Result<(), Error> {
+    let mut readme = String::from_utf8_lossy(&fs::read(&config().readme.path)?).to_string();
+    update_content(&mut readme)?;
+    fs::write(&config().readme.path, &readme)?;
+    Ok(())
+}
@@ -1,62 +1,18 @@
-/// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
-
-use crate::template::Day;
+use crate::template::readme::{self, Error};
 use crate::template::timings::Timings;
-
-static MARKER: &str = "<!--- benchmarking table --->";
-
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    Parser(String),
-    IO(io::Error),
-}
-
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
-
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
-}
+/// Module that updates the readme me with timing information.
+use crate::template::{Day, config};
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
-
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
-    }
-
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
-
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
-
-    Ok(TablePosition { pos_start, pos_end })
-}
-
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(marker: &str, prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.into(),
         header,
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
@@ -76,32 +32,34 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let table = construct_table(marker, "##", timings, total_millis);
+    readme::replace_table(s, marker, &table)
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
-    Ok(())
+    let marker = &config().readme.benchmarks_marker;
+    readme::update(|s| update_content(s, marker, timings, total_millis))
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
+    use super::update_content;
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
+    static MARKER: &str = "<!--- benchmarking table --->";
+
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
@@ -131,28 +89,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +118,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -0,0 +1,156 @@
+/// Module that updates the readme with advent of code progress.
+/// Replaces the table otherwise maintained by the `advent-readme-stars` Github action.
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::readme::{self, Error};
+use crate::template::{Day, all_days, config};
+
+/// Represents the stars earned for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+impl Stars {
+    pub fn count(&self) -> u8 {
+        u8::from(self.part_1) + u8::from(self.part_2)
+    }
+}
+
+/// Count the answers listed on a downloaded puzzle page.
+/// The page shows "Your puzzle answer was ..." for every solved part.
+fn count_answers_in_puzzle(puzzle: &str) -> usize {
+    puzzle.matches("Your puzzle answer was").count()
+}
+
+/// Determine the stars earned for a day.
+/// Stored submission verdicts take precedence, the downloaded puzzle page is used as a fallback.
+pub fn for_day(day: Day, answers: &Answers) -> Stars {
+    let solved_on_page = fs::read_to_string(config().puzzle_path(day))
+        .map(|puzzle| count_answers_in_puzzle(&puzzle))
+        .unwrap_or(0);
+
+    Stars {
+        day,
+        part_1: answers.is_part_solved(day, 1) || solved_on_page >= 1,
+        part_2: answers.is_part_solved(day, 2) || solved_on_page >= 2,
+    }
+}
+
+/// Collect stars for every day that has at least one.
+pub fn collect(answers: &Answers) -> Vec<Stars> {
+    all_days()
+        .map(|day| for_day(day, answers))
+        .filter(|stars| stars.count() > 0)
+        .collect()
+}
+
+fn construct_table(marker: &str, prefix: &str, stars: &[Stars], year: Option<u16>) -> String {
+    let header = match year {
+        Some(year) => format!("{prefix} {year} Results"),
+        None => format!("{prefix} Results"),
+    };
+
+    let mut lines: Vec<String> = vec![
+        marker.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day_stars in stars {
+        let day = day_stars.day.into_inner();
+        let label = match year {
+            Some(year) => format!("[Day {day}](https://adventofcode.com/{year}/day/{day})"),
+            None => format!("Day {day}"),
+        };
+        let star = |solved: bool| if solved { "⭐" } else { " " };
+        lines.push(format!(
+            "| {label} | {} | {} |",
+            star(day_stars.part_1),
+            star(day_stars.part_2)
+        ));
+    }
+
+    lines.push(marker.into());
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    stars: &[Stars],
+    year: Option<u16>,
+) -> Result<(), Error> {
+    let table = construct_table(marker, "##", stars, year);
+    readme::replace_table(s, marker, &table)
+}
+
+pub fn update(stars: &[Stars], year: Option<u16>) -> Result<(), Error> {
+    let marker = &config().readme.stars_marker;
+    readme::update(|s| update_content(s, marker, stars, year))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Stars, count_answers_in_puzzle, update_content};
+    use crate::day;
+
+    static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+    fn get_mock_stars() -> Vec<Stars> {
+        vec![
+            Stars {
+                day: day!(1),
+                part_1: true,
+                part_2: true,
+            },
+            Stars {
+                day: day!(2),
+                part_1: true,
+                part_2: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn counts_answers_in_puzzle() {
+        let puzzle = "--- Day 1 ---\nYour puzzle answer was `3`.\n--- Part Two ---\nYour puzzle answer was `6`.";
+        assert_eq!(count_answers_in_puzzle(puzzle), 2);
+        assert_eq!(count_answers_in_puzzle("--- Day 1 ---"), 0);
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        update_content(&mut s, MARKER, &get_mock_stars(), Some(2025)).unwrap();
+        update_content(&mut s, MARKER, &get_mock_stars(), Some(2025)).unwrap();
+        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## 2025 Results").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        update_content(&mut s, MARKER, &get_mock_stars(), Some(2025)).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
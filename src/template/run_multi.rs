@@ -1,62 +1,82 @@
-use std::{collections::HashSet, io};
+use std::collections::HashSet;
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Error};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// The outcome of running several days.
+pub struct MultiRun {
+    /// Timings of the days that ran successfully, if the run was timed.
+    pub timings: Option<Timings>,
+    /// The first day that failed to build or to run.
+    pub failure: Option<Error>,
+}
+
+impl MultiRun {
+    /// Turn the first failure into an error, e.g. to propagate its exit code.
+    pub fn result(self) -> Result<(), Error> {
+        self.failure.map_or(Ok(()), Err)
+    }
+}
+
+/// Run several days. A day that fails to build or to run does not stop the remaining ones:
+/// the first failure is returned alongside the timings that were collected.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+) -> Result<MultiRun, Error> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
+    let mut failure = None;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+    for day in all_days().filter(|day| days_to_run.contains(day)) {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let output = match child_commands::run_solution(day, is_timed, is_release) {
+            Ok(output) => output,
+            // keep running the remaining days and report the first failure at the end.
+            Err(
+                e @ (Error::BuildFailed { .. }
+                | Error::InputMissing { .. }
+                | Error::SolutionFailed { .. }),
+            ) => {
+                eprintln!("{e}");
+                failure.get_or_insert(e);
+                continue;
             }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            Err(e) => return Err(e),
+        };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+        if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::parse_exec_time(&output, day);
+            timings.push(val);
+        }
+    }
 
-    if is_timed {
+    let timings = is_timed.then(|| {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
-    }
-}
+        timings
+    });
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
-}
-
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
+    Ok(MultiRun { timings, failure })
 }
 
 #[must_use]
@@ -67,15 +87,41 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::get_path_for_bin;
+    use crate::template::{Day, Error};
     use std::{
-        io::{BufRead, BufReader},
+        io::{self, BufRead, BufReader},
         path::Path,
-        process::{Command, Stdio},
+        process::{Command, ExitStatus, Stdio},
         thread,
     };
 
+    /// Build the solution bin for a given day, passing `profile_args` to `cargo build`.
+    pub fn build_solution(day: Day, profile_args: &[String], quiet: bool) -> Result<(), Error> {
+        let day_padded = day.to_string();
+        let mut args = vec!["build", "--bin", &day_padded];
+
+        if quiet {
+            args.push("--quiet");
+        }
+
+        args.extend(profile_args.iter().map(String::as_str));
+
+        let status = Command::new("cargo")
+            .args(&args)
+            .status()
+            .map_err(|source| Error::Command {
+                command: format!("cargo {}", args.join(" ")),
+                source,
+            })?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::BuildFailed { day })
+        }
+    }
+
     /// Run the solution bin for a given day
     pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
@@ -83,6 +129,26 @@ pub mod child_commands {
             return Ok(vec![]);
         }
 
+        let profile_args = if is_release {
+            vec!["--release".to_string()]
+        } else {
+            vec![]
+        };
+        build_solution(day, &profile_args, true)?;
+
+        let (output, status) = collect_output(day, is_timed, is_release)
+            .map_err(|source| Error::Solution { day, source })?;
+        Error::from_solution_status(day, status)?;
+
+        Ok(output)
+    }
+
+    /// Run the solution bin, forwarding its output while grabbing stdout lines.
+    fn collect_output(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> io::Result<(Vec<String>, ExitStatus)> {
         let day_padded = day.to_string();
         let mut args = vec!["run", "--quiet", "--bin", &day_padded];
 
@@ -105,27 +171,27 @@ pub mod child_commands {
             .stderr(Stdio::piped())
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let stdout = BufReader::new(cmd.stdout.take().ok_or(io::ErrorKind::BrokenPipe)?);
+        let stderr = BufReader::new(cmd.stderr.take().ok_or(io::ErrorKind::BrokenPipe)?);
 
         let mut output = vec![];
 
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
             });
         });
 
         for line in stdout.lines() {
-            let line = line.unwrap();
+            let line = line?;
             println!("{line}");
             output.push(line);
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        let status = cmd.wait()?;
 
-        Ok(output)
+        Ok((output, status))
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
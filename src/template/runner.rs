@@ -1,15 +1,23 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
+use std::io::{Write, stdin, stdout};
 use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{Answers, Submission, Verdict};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli, config};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Run a solution part, submitting the result if requested.
+/// Returns the verdict for the answer if it was submitted or is known from earlier submissions.
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+) -> Option<Verdict> {
     let part_str = format!("Part {part}");
 
     let (result, duration, samples) =
@@ -17,14 +25,13 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
-    }
+    submit_result(&result?, day, part)
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     The budget can be changed in the `[bench]` section of `aoc.toml`.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
@@ -56,8 +63,9 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench = &config().bench;
+    let bench_iterations = (bench.duration.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(bench.min_samples.into(), bench.max_samples.into());
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -128,11 +136,8 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  3. the answer passes the safety checks configured in the `[submit]` section of `aoc.toml`.
+fn submit_result<T: Display>(result: &T, day: Day, part: u8) -> Option<Verdict> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -162,6 +167,62 @@ fn submit_result<T: Display>(
         process::exit(1);
     }
 
+    let answer = result.to_string();
+
+    let answers = Answers::read_from_file();
+    if config().submit.check_known_answers
+        && let Some(reason) = answers.check(day, part, &answer)
+    {
+        eprintln!("Not submitting {answer}: {reason}");
+        return if answers.correct_answer(day, part) == Some(answer.as_str()) {
+            Some(Verdict::Correct)
+        } else {
+            Some(Verdict::Incorrect)
+        };
+    }
+
+    if config().submit.confirm && !confirm(&format!("Submit {answer} for day {day}, part {part}?"))
+    {
+        return None;
+    }
+
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let output = aoc_cli::submit(day, part, &answer).ok()?;
+    record_submission(&output, &answer, day, part)
+}
+
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut reply = String::new();
+    if stdin().read_line(&mut reply).is_err() {
+        return false;
+    }
+
+    matches!(reply.trim(), "y" | "Y" | "yes")
+}
+
+/// Store the verdict of a submission so progress can be tracked locally.
+fn record_submission(output: &Output, answer: &str, day: Day, part: u8) -> Option<Verdict> {
+    let response = String::from_utf8_lossy(&output.stdout);
+
+    let Some(verdict) = Verdict::from_response(&response) else {
+        eprintln!("Could not determine the verdict of the submission, it will not be stored.");
+        return None;
+    };
+
+    let mut answers = Answers::read_from_file();
+    answers.record(Submission {
+        day,
+        part,
+        answer: answer.into(),
+        verdict,
+    });
+
+    if let Err(e) = answers.store_file() {
+        eprintln!("Failed to store submission verdict: {e}");
+    }
+
+    Some(verdict)
 }
@@ -0,0 +1,132 @@
+/// Helpers for the tests generated by [`solution_tests!`](crate::solution_tests).
+use std::fmt::Display;
+
+use crate::template::answers::Answers;
+use crate::template::{Day, Input, InputError, examples, read_input};
+
+/// The example file a test case refers to: `example` is the day's default example, e.g. `01.txt`,
+/// any other name `<name>` refers to `<day>-<name>.txt`.
+pub fn example_name(day: Day, case: &str) -> String {
+    if case == "example" {
+        day.to_string()
+    } else {
+        format!("{day}-{case}")
+    }
+}
+
+/// Read an example by name, e.g. `11-1`. Panics if it is missing or empty.
+pub fn read_example(day: Day, name: &str) -> Input {
+    examples::all(day)
+        .unwrap_or_else(|e| panic!("{e}"))
+        .into_iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("example `{name}` does not exist."))
+        .input
+}
+
+/// Run `func` against the real input and compare it with the answer accepted on submission.
+/// Skipped if the input was not downloaded yet or no answer was accepted so far.
+pub fn check_real_input<T: Display>(day: Day, part: u8, func: impl Fn(&Input) -> Option<T>) {
+    let answers = Answers::read_from_file();
+    let Some(expected) = answers.correct_answer(day, part) else {
+        println!("no accepted answer for day {day}, part {part}, skipped.");
+        return;
+    };
+
+    let input = match read_input("inputs", day) {
+        Ok(input) => Input::real(input),
+        Err(e @ (InputError::Missing { .. } | InputError::Empty { .. })) => {
+            println!("{e} Skipped.");
+            return;
+        }
+        Err(e) => panic!("{e}"),
+    };
+
+    let result = func(&input).map(|result| result.to_string());
+    assert_eq!(result.as_deref(), Some(expected));
+}
+
+/// Generates named tests for the parts of a solution:
+///  - `<name> => <expected>` runs the part against an example, see [`example_name`].
+///  - `<name>("<file>") => <expected>` does the same for an example with any name, e.g. `11-1`.
+///  - `real_input` runs the part against the real input and compares it with the accepted answer.
+///
+/// The tests are named after the part and the case, e.g. `tests::part_one::example`.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     advent_of_code::solution_tests! {
+///         part_one {
+///             example => Some(5),
+///             larger("11-large") => Some(1337),
+///             real_input,
+///         }
+///         part_two {
+///             example => None,
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! solution_tests {
+    ($( $func:ident { $($cases:tt)* } )+) => {
+        $(
+            mod $func {
+                $crate::solution_tests!(@cases $func, $($cases)*);
+            }
+        )+
+    };
+
+    (@cases $func:ident $(,)?) => {};
+
+    (@cases $func:ident, real_input $(, $($rest:tt)*)?) => {
+        #[test]
+        fn real_input() {
+            $crate::template::testing::check_real_input(
+                super::DAY,
+                $crate::example_tests!(@part $func),
+                |input: &$crate::template::Input| super::$func(input),
+            );
+        }
+
+        $crate::solution_tests!(@cases $func, $($($rest)*)?);
+    };
+
+    (@cases $func:ident, $case:ident($name:literal) => $expected:expr $(, $($rest:tt)*)?) => {
+        #[test]
+        fn $case() {
+            let input = $crate::template::testing::read_example(super::DAY, $name);
+            assert_eq!(super::$func(&input), $expected);
+        }
+
+        $crate::solution_tests!(@cases $func, $($($rest)*)?);
+    };
+
+    (@cases $func:ident, $case:ident => $expected:expr $(, $($rest:tt)*)?) => {
+        #[test]
+        fn $case() {
+            let name = $crate::template::testing::example_name(super::DAY, stringify!($case));
+            let input = $crate::template::testing::read_example(super::DAY, &name);
+            assert_eq!(super::$func(&input), $expected);
+        }
+
+        $crate::solution_tests!(@cases $func, $($($rest)*)?);
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::example_name;
+    use crate::day;
+
+    #[test]
+    fn maps_cases_to_examples() {
+        assert_eq!(example_name(day!(1), "example"), "01");
+        assert_eq!(example_name(day!(11), "large"), "11-large");
+    }
+}
@@ -0,0 +1,166 @@
+//! A disjoint-set forest for grouping elements `0..n` into components.
+//!
+//! Uses path compression and union by size, so every operation runs in nearly constant time.
+//!
+//! ```
+//! # use advent_of_code::union_find::UnionFind;
+//! let mut circuits = UnionFind::new(5);
+//! circuits.union(0, 1);
+//! circuits.union(3, 1);
+//! assert!(circuits.connected(0, 3));
+//! assert_eq!(circuits.component_size(3), 3);
+//! assert_eq!(circuits.component_count(), 3);
+//! ```
+
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// The size of each component, only valid for roots.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Create `n` components with one element each.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Add a new element in its own component and return it.
+    pub fn push(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.components += 1;
+        element
+    }
+
+    /// The representative of the component containing `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // path compression: point every element on the way directly to the root.
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merge the components containing `a` and `b`.
+    /// Returns `false` if they already were in the same component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // attach the smaller tree below the larger one to keep the trees flat.
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the component containing `element`.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every component, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// The elements of every component, each sorted ascending.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut by_root: Vec<Vec<usize>> = vec![vec![]; self.len()];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            by_root[root].push(element);
+        }
+        by_root.retain(|component| !component.is_empty());
+        by_root
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn merges_components() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 2));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.component_size(3), 4);
+
+        let mut sizes = sets.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, [1, 1, 4]);
+        assert_eq!(sets.components(), [vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn grows() {
+        let mut sets = UnionFind::new(0);
+        assert!(sets.is_empty());
+        let a = sets.push();
+        let b = sets.push();
+        sets.union(a, b);
+        assert_eq!((sets.len(), sets.component_count()), (2, 1));
+    }
+
+    #[test]
+    fn compresses_long_chains() {
+        let n = 100_000;
+        let mut sets = UnionFind::new(n);
+        for i in 1..n {
+            sets.union(i - 1, i);
+        }
+        assert_eq!(sets.component_size(0), n);
+        assert_eq!(sets.component_count(), 1);
+    }
+}